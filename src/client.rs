@@ -1,28 +1,339 @@
-use crate::models::signers::{Signer, SignerToDocument};
-use crate::models::documents::Document;
-use error_chain::bail;
-use reqwest::Response;
-use reqwest::StatusCode;
-use std::collections::HashMap;
-
-/// This struct defines a clicksign Client
+use crate::crypto::sign_secret_hmac;
+use crate::environment::{self, ApiVersion, Environment, Url};
+use crate::error::Error;
+use crate::models::batches::Batch;
+use crate::models::envelopes::{BatchEnvelope, DocumentEnvelope, ListEnvelope, SignerEnvelope};
+use crate::models::notifications::{Notification, NotificationChannel, NotificationResult};
+use crate::models::signers::{NewSigner, SignerResponse, SignerToDocument};
+use crate::models::keys::{BatchKey, DocumentKey, ListKey, RequestSignatureKey, SignerKey};
+use crate::models::documents::{
+    Document, DocumentConfiguration, DocumentFilter, DocumentsPage, NewDocument,
+};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use crate::rate_limit::{retry_after, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::secret::AccessToken;
+use crate::validation::{normalize_phone_number, FieldError, INVALID_PHONE_NUMBER};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Proxy, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Given the raw content of a PDF file, returns it as a base64 data URI
+fn encode_pdf(content: &[u8]) -> String {
+    use base64::Engine;
+    format!(
+        "data:application/pdf;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(content)
+    )
+}
+
+/// This struct defines a clicksign Client.
+/// Its `Debug` output redacts the access token.
 #[derive(Debug)]
 pub struct Client {
-    /// * host (String): The base URL for clicksign API
-    pub host: String,
-    /// * access_token (String): Access token provided by clicksign. Follow [this instructions](https://developers.clicksign.com/docs/pimeiros-passos#2-gere-um-access-token) to generate your token.
-    pub access_token: String,
+    /// * environment (Environment): The clicksign environment requests are sent to
+    environment: Environment,
+    /// * api_version (ApiVersion): The version of the clicksign API
+    api_version: ApiVersion,
+    /// * base_url (Url): The environment host followed by the API version path
+    base_url: Url,
+    /// * access_token (AccessToken): Access token provided by clicksign. Follow [this instructions](https://developers.clicksign.com/docs/pimeiros-passos#2-gere-um-access-token) to generate your token.
+    access_token: AccessToken,
     /// * client: A reqwest http client.
-    pub client: reqwest::Client,
+    client: reqwest::Client,
+    /// * retry_policy: How requests failed with transient errors are retried.
+    retry_policy: RetryPolicy,
+    /// * rate_limiter: Limits the rate of requests sent, when enabled.
+    rate_limiter: Option<RateLimiter>,
+}
+
+/// `User-Agent` sent by the client unless another one is configured
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Default time limit to establish a connection with clicksign
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Default time limit for a whole request, from connecting to reading the response body
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Builder for a clicksign Client, configuring the underlying HTTP client.
+/// Created by `Client::builder`.
+///
+/// # Example
+/// ```
+/// use clicksign::client::Client;
+/// use clicksign::environment::Environment;
+/// use std::time::Duration;
+///
+/// let client = Client::builder("some_access_token")
+///     .environment(Environment::Sandbox)
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .build()
+///     .unwrap();
+/// assert_eq!("https://sandbox.clicksign.com/api/v1/", client.base_url().as_str());
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    environment: Environment,
+    host: Option<String>,
+    api_version: ApiVersion,
+    access_token: AccessToken,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    user_agent: String,
+    default_headers: HeaderMap,
+    http_client: Option<reqwest::Client>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+}
+
+impl ClientBuilder {
+    /// Given an access_token, creates a builder with the default settings: the clicksign
+    /// production environment, a 10 seconds connect timeout and a 60 seconds request timeout.
+    pub fn new(access_token: &str) -> Self {
+        Self {
+            environment: Environment::default(),
+            host: None,
+            api_version: ApiVersion::default(),
+            access_token: AccessToken::new(access_token),
+            connect_timeout: Some(DEFAULT_CONNECT_TIMEOUT),
+            timeout: Some(DEFAULT_TIMEOUT),
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            user_agent: USER_AGENT.to_string(),
+            default_headers: HeaderMap::new(),
+            http_client: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Given a clicksign environment, returns the builder using it
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self.host = None;
+        self
+    }
+
+    /// Given the URL of a custom host (e.g. a mock server), returns the builder using it.
+    /// It is parsed when the client is built, as `Environment::Custom`. A host without
+    /// scheme, e.g. `api.example.com`, uses https, and a host already ending with the API
+    /// version path, e.g. `https://app.clicksign.com/api/v1/`, doesn't get it twice.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Given an API version, returns the builder using it
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Given a duration, limits the time to establish a connection. `None` waits indefinitely.
+    pub fn connect_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.connect_timeout = timeout.into();
+        self
+    }
+
+    /// Given a duration, limits the time of each request, from connecting to reading the
+    /// response body. `None` waits indefinitely.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Given a proxy, sends the requests through it
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::client::Client;
+    ///
+    /// let proxy = reqwest::Proxy::https("http://proxy.example.com:3128").unwrap();
+    /// let client = Client::builder("some_access_token").proxy(proxy).build().unwrap();
+    /// ```
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Given a certificate, trusts it as a root certificate, besides the system ones
+    ///
+    /// # Example
+    /// ```no_run
+    /// use clicksign::client::Client;
+    ///
+    /// let pem = std::fs::read("corporate-ca.pem").unwrap();
+    /// let certificate = reqwest::Certificate::from_pem(&pem).unwrap();
+    /// let client = Client::builder("some_access_token")
+    ///     .add_root_certificate(certificate)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Given a user agent, sends it instead of the default `clicksign-rs/<version>`
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Given a header name and value, sends the header in every request
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Given a pre-built reqwest client, sends the requests with it, e.g. to share its
+    /// connection pool with the rest of the application.
+    /// The client is used as is: the timeouts, proxies, root certificates, user agent and
+    /// default headers of this builder are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::client::Client;
+    ///
+    /// let http_client = reqwest::Client::new();
+    /// let client = Client::builder("some_access_token")
+    ///     .http_client(http_client.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Given a retry policy, returns the builder using it
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Given a rate limiter, returns the builder using it
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Creates the client, failing when the host is not a valid http(s) URL or the HTTP
+    /// client can't be built with the given settings
+    pub fn build(self) -> Result<Client, Error> {
+        let environment = match &self.host {
+            Some(host) => Environment::Custom(parse_host(host)?),
+            None => self.environment,
+        };
+        let base_url = match environment::base_url(&environment, self.api_version) {
+            Some(base_url) => base_url,
+            None => {
+                return Err(Error::InvalidInput(format!(
+                    "The host must be an http(s) URL: {}",
+                    environment.host()
+                )))
+            }
+        };
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder()
+                    .user_agent(self.user_agent)
+                    .default_headers(self.default_headers);
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                for certificate in self.root_certificates {
+                    builder = builder.add_root_certificate(certificate);
+                }
+                builder.build().map_err(Error::Builder)?
+            }
+        };
+        Ok(Client {
+            environment,
+            api_version: self.api_version,
+            base_url,
+            access_token: self.access_token,
+            client,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+        })
+    }
+}
+
+/// Given the URL of a custom host, returns it parsed, using https when it has no scheme
+fn parse_host(host: &str) -> Result<Url, Error> {
+    let url = match Url::parse(host) {
+        Err(url::ParseError::RelativeUrlWithoutBase) => Url::parse(&format!("https://{}", host)),
+        url => url,
+    };
+    url.map_err(|e| Error::InvalidInput(format!("Invalid host {}: {}", host, e)))
+}
+
+/// Given a response body, deserializes it keeping the raw body in case of errors
+fn decode<T: DeserializeOwned>(body: &str) -> Result<T, Error> {
+    serde_json::from_str(body).map_err(|e| Error::decode(e, body))
+}
+
+/// Given the signer and the list relating it to a document, returns the notification
+/// to be sent by phone, checking the signer has a valid phone number, as `NewSigner::validate`
+fn phone_notification(
+    list: &SignerToDocument,
+    signer: &SignerResponse,
+) -> Result<Notification, Error> {
+    let request_signature_key = match &list.request_signature_key {
+        Some(key) => key,
+        None => {
+            return Err(Error::InvalidInput(
+                "The list has no request_signature_key".to_string(),
+            ))
+        }
+    };
+    let error = match signer.phone_number.as_deref() {
+        Some(phone_number) if normalize_phone_number(phone_number).is_some() => None,
+        Some(phone_number) if !phone_number.is_empty() => Some(INVALID_PHONE_NUMBER),
+        _ => Some("is required for SMS and Whatsapp notifications"),
+    };
+    if let Some(message) = error {
+        return Err(Error::InvalidFields(vec![FieldError::new(
+            "phone_number",
+            message,
+        )]));
+    }
+    Ok(Notification {
+        request_signature_key: request_signature_key.clone(),
+        message: None,
+        url: None,
+    })
 }
 
 /// Implementation for client struct
 impl Client {
-    /// Given an access_token and an optional host, creates a Client instance.
+    /// Given an access_token and an optional host, creates a Client instance
+    /// with the default settings of `ClientBuilder`.
+    /// Without a host, requests are sent to the production environment.
     ///
     /// # Arguments
     /// * access_token (&str): Access token provided by clicksign.
-    /// * host (&str, optional): The base URL for clicksign API.
+    /// * host (&str, optional): The URL of a custom host for clicksign API.
+    ///
+    /// # Panics
+    /// When the host can't be parsed as an http(s) URL, e.g. `not a url`.
+    /// Use `Client::builder(access_token).host(host).build()` to handle the error instead.
     ///
     /// # Example
     /// ```
@@ -32,18 +343,112 @@ impl Client {
     ///    "c9d91ece-9b3b-4def-abac-25b645cb083c",
     ///    Some("https://api.example.com"),
     /// );
-    /// assert_eq!("https://api.example.com", client.host);
-    /// assert_eq!("c9d91ece-9b3b-4def-abac-25b645cb083c", client.access_token);
+    /// assert_eq!("https://api.example.com/api/v1/", client.base_url().as_str());
+    /// assert_eq!("c9d91ece-9b3b-4def-abac-25b645cb083c", client.access_token().expose_secret());
+    ///
+    /// let client = Client::new("c9d91ece-9b3b-4def-abac-25b645cb083c", Some("api.example.com/api/v1"));
+    /// assert_eq!("https://api.example.com/api/v1/", client.base_url().as_str());
     /// ```
     pub fn new(access_token: &str, host: Option<&str>) -> Self {
-        Self {
-            host: host.unwrap_or("https://app.clicksign.com/").to_string(),
-            access_token: access_token.to_string(),
-            client: reqwest::Client::new(),
+        let mut builder = Self::builder(access_token);
+        if let Some(host) = host {
+            builder = builder.host(host);
+        }
+        match builder.build() {
+            Ok(client) => client,
+            Err(e) => panic!("The client could not be built: {}", e),
         }
     }
 
-    /// Given a path (endpoint), generates a full url based on host.
+    /// Given an access_token, returns a builder to configure the client
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::client::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::builder("some_access_token")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!("https://app.clicksign.com/api/v1/", client.base_url().as_str());
+    /// ```
+    pub fn builder(access_token: &str) -> ClientBuilder {
+        ClientBuilder::new(access_token)
+    }
+
+    /// Given a retry policy, returns the client using it
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::client::Client;
+    /// use clicksign::retry::RetryPolicy;
+    ///
+    /// let client = Client::new("some_access_token", None).with_retry_policy(RetryPolicy::none());
+    /// assert_eq!(1, client.retry_policy().max_attempts);
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Given a rate limiter, returns the client using it.
+    /// Every request waits for the limiter before being sent and, when clicksign answers
+    /// with 429, the limiter is paused for the time informed in the response headers and
+    /// the request is sent again, whatever its method, up to the retry policy attempts.
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::client::Client;
+    /// use clicksign::rate_limit::RateLimiter;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new("some_access_token", None)
+    ///     .with_rate_limiter(RateLimiter::new(10, 5, Duration::from_secs(1)));
+    /// assert!(client.rate_limiter().is_some());
+    /// ```
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the clicksign environment requests are sent to
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// Returns the version of the clicksign API
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Returns the base URL of the API, with the environment host and the API version path
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Returns the access token, whose `Debug` and `Display` outputs are redacted
+    pub fn access_token(&self) -> &AccessToken {
+        &self.access_token
+    }
+
+    /// Returns the reqwest client used to send the requests
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Returns how requests failed with transient errors are retried
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns the rate limiter, when enabled
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Given a path (endpoint), generates a full url based on the environment and API version.
+    /// Each segment of the path and the access token are percent-encoded.
     ///
     /// # Example
     /// ```
@@ -51,41 +456,86 @@ impl Client {
     ///
     /// let client = Client::new(
     ///    "some_access_token",
-    ///    Some("https://api.example.com/"),
+    ///    Some("https://api.example.com"),
+    /// );
+    /// let new_url = client.build_url("documents/some_key");
+    /// assert_eq!(
+    ///     "https://api.example.com/api/v1/documents/some_key?access_token=some_access_token",
+    ///     new_url.as_str()
     /// );
-    /// let new_url = client.build_url("foo");
-    /// assert_eq!("https://api.example.com/foo?access_token=some_access_token", new_url);
     /// ```
-    pub fn build_url(&self, endpoint: &str) -> String {
-        format!(
-            "{}{}?access_token={}",
-            self.host, endpoint, self.access_token
-        )
+    pub fn build_url(&self, endpoint: &str) -> Url {
+        let mut url = self.base_url.clone();
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments
+                .pop_if_empty()
+                .extend(endpoint.trim_start_matches('/').split('/'));
+        }
+        url.query_pairs_mut()
+            .append_pair("access_token", self.access_token.expose_secret());
+        url
     }
 
-    /// Given a Response object, return the body content or the appropriate message error
-    async fn handler(&self, response: Response) -> Result<String, Box<dyn std::error::Error>> {
-        match response.status() {
-            StatusCode::CREATED | StatusCode::OK | StatusCode::ACCEPTED => {
-                Ok(response.text().await.unwrap())
-            }
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("500 Internal Server Error")
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("503 Service Unavailable")
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("401 Unauthorized")
-            }
-            StatusCode::FORBIDDEN => {
-                bail!("403 Forbidden")
-            }
-            StatusCode::BAD_REQUEST => {
-                bail!("400 Bad Request: {}", response.text().await.unwrap())
+    /// Given a Response object, return the body content or the appropriate error
+    async fn handler(&self, response: Response) -> Result<String, Error> {
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let body = response.text().await?;
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(Error::from_response(status, retry_after, body))
+        }
+    }
+
+    /// Given a request, sends it once, returning the result and the `Retry-After` of the response
+    async fn attempt(&self, request: Request) -> (Result<String, Error>, Option<Duration>) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        match self.client.execute(request).await {
+            Ok(response) => {
+                let retry_after = retry_after(response.headers());
+                (self.handler(response).await, retry_after)
             }
-            resp => {
-                bail!(format!("Received response: {:?}", resp))
+            Err(e) => (Err(e.into()), None),
+        }
+    }
+
+    /// Given a request, sends it retrying transient failures according to the retry policy
+    async fn send(&self, request: RequestBuilder) -> Result<String, Error> {
+        let request = request.build()?;
+        let max_attempts = self.retry_policy.attempts_for(request.method());
+        let mut attempt = 1;
+        loop {
+            let current = match request.try_clone() {
+                Some(current) => current,
+                None => return self.attempt(request).await.0,
+            };
+            let (result, retry_after) = self.attempt(current).await;
+            match (result, &self.rate_limiter) {
+                // A 429 means the request was not processed, so it is safe to send it again
+                (Err(Error::RateLimited { retry_after }), Some(rate_limiter))
+                    if attempt < self.retry_policy.max_attempts =>
+                {
+                    let wait = match retry_after {
+                        Some(retry_after) => retry_after.min(self.retry_policy.max_delay),
+                        None => self.retry_policy.delay(attempt, None),
+                    };
+                    rate_limiter.pause(wait).await;
+                    attempt += 1;
+                }
+                (Err(e), _) if attempt < max_attempts && self.retry_policy.is_retryable(&e) => {
+                    tokio::time::sleep(self.retry_policy.delay(attempt, retry_after)).await;
+                    attempt += 1;
+                }
+                (Err(e), _) if attempt > 1 => {
+                    return Err(Error::Retried {
+                        attempts: attempt,
+                        last_error: Box::new(e),
+                    })
+                }
+                (result, _) => return result,
             }
         }
     }
@@ -94,180 +544,664 @@ impl Client {
     /// Reference: <https://developers.clicksign.com/docs/criar-documento-via-modelos>
     ///
     /// # Arguments
-    /// * template_id (&str): The ID of the template for which you want to create the document.
-    /// * template_body (&str): A json-like string to fill in the document template
+    /// * document (Document): The new document, with its `path` and the `template` to fill in
     ///
     /// # Example
     /// ```no_run
     /// async {
     ///   use clicksign::client::Client;
     ///   use std::collections::HashMap;
-    ///   use clicksign::models::documents::Document;
+    ///   use clicksign::models::documents::{Document, DocumentTemplate};
     ///
     ///   let client = Client::new(
     ///      "some_access_token",
     ///      Some("https://api.example.com/"),
     ///   );
-    ///   let template_body = r#"
-    ///      {
-    ///        "document": {
-    ///          "path": "/Modelos/Teste-123.docx",
-    ///          "template": {
-    ///            "data": {
-    ///              "Company Name": "Clicksign Gestão de Documentos S.A.",
-    ///              "Address": "R. Teodoro Sampaio 2767, 10° andar",
-    ///              "Phone": "(11) 3145-2570",
-    ///              "Website": "https://www.clicksign.com"
-    ///            }
-    ///          }
-    ///        }
-    ///      }
-    ///  "#;
-    ///  let value: HashMap<String, Document> = serde_json::from_str(template_body).unwrap();
-    ///  let document = client.create_document_by_model(value)
+    ///   let mut data = HashMap::new();
+    ///   data.insert("Company Name".to_string(), "Clicksign Gestão de Documentos S.A.".to_string());
+    ///   data.insert("Phone".to_string(), "(11) 3145-2570".to_string());
+    ///   let document = Document {
+    ///       path: "/Modelos/Teste-123.docx".to_string(),
+    ///       template: Some(DocumentTemplate {
+    ///           key: "e4c6b5b1-3a5c-4a42-9c1e-6f1b0d7c2a10".parse().unwrap(),
+    ///           data,
+    ///       }),
+    ///       ..Default::default()
+    ///   };
+    ///   let document = client.create_document_by_model(document)
     ///      .await
     ///      .unwrap();
     ///  };
     /// ```
     pub async fn create_document_by_model(
         &self,
-        request_body: HashMap<String, Document>
-    ) -> Result<HashMap<String, Document>, Box<dyn std::error::Error>> {
-        let template_id = &request_body.get("document").unwrap().template.key;
+        document: Document,
+    ) -> Result<Document, Error> {
+        let template_id = match &document.template {
+            Some(template) => template.key.clone(),
+            None => {
+                return Err(Error::InvalidInput(
+                    "The document must have a template".to_string(),
+                ))
+            }
+        };
         let url = self.build_url(
             &format!("templates/{}/documents", template_id)
         );
-        let resp = self
+        let request_body = DocumentEnvelope { document };
+        let request = self
             .client
             .post(url)
             .json(&request_body)
-            .header("Content-Type", "application/json")
-            .send()
-            .await?;
+            .header("Content-Type", "application/json");
+
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Create a new document, uploading the content of a PDF file
+    /// Reference: <https://developers.clicksign.com/docs/criar-documento-via-upload>
+    ///
+    /// # Arguments
+    /// * document (NewDocument): Metainformation of the new document
+    /// * content (&[u8]): Raw content of the PDF file
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::documents::NewDocument;
+    ///   use clicksign::models::enums::Locale;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = NewDocument::new("/Contratos/Contrato-123.pdf")
+    ///       .deadline_at("2021-12-31T14:30:59-03:00".parse().unwrap())
+    ///       .auto_close(true)
+    ///       .locale(Locale::PtBr);
+    ///   let content = std::fs::read("Contrato-123.pdf").unwrap();
+    ///   let document = client.create_document_by_upload(document, &content)
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn create_document_by_upload(
+        &self,
+        mut document: NewDocument,
+        content: &[u8],
+    ) -> Result<Document, Error> {
+        document.content_base64 = Some(encode_pdf(content));
+        let request_body = DocumentEnvelope { document };
+
+        let url = self.build_url("documents");
+        let request = self
+            .client
+            .post(url)
+            .json(&request_body)
+            .header("Content-Type", "application/json");
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Create a new document, uploading a PDF file from the local filesystem
+    /// Reference: <https://developers.clicksign.com/docs/criar-documento-via-upload>
+    ///
+    /// # Arguments
+    /// * document (NewDocument): Metainformation of the new document
+    /// * file_path (AsRef<Path>): Path of the PDF file to be uploaded
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::documents::NewDocument;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = NewDocument::new("/Contratos/Contrato-123.pdf");
+    ///   let document = client
+    ///       .create_document_by_upload_from_path(document, "Contrato-123.pdf")
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn create_document_by_upload_from_path<P: AsRef<Path>>(
+        &self,
+        document: NewDocument,
+        file_path: P,
+    ) -> Result<Document, Error> {
+        let content = tokio::fs::read(file_path).await?;
+        self.create_document_by_upload(document, &content).await
+    }
+
+    /// Create a new document, uploading a PDF file read from an async reader
+    /// Reference: <https://developers.clicksign.com/docs/criar-documento-via-upload>
+    ///
+    /// # Arguments
+    /// * document (NewDocument): Metainformation of the new document
+    /// * reader (AsyncRead): Reader with the content of the PDF file
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::documents::NewDocument;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = NewDocument::new("/Contratos/Contrato-123.pdf");
+    ///   let file = tokio::fs::File::open("Contrato-123.pdf").await.unwrap();
+    ///   let document = client
+    ///       .create_document_by_upload_from_reader(document, file)
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn create_document_by_upload_from_reader<R: AsyncRead + Unpin>(
+        &self,
+        document: NewDocument,
+        mut reader: R,
+    ) -> Result<Document, Error> {
+        let mut content = Vec::new();
+        reader.read_to_end(&mut content).await?;
+        self.create_document_by_upload(document, &content).await
+    }
+
+    /// View a document, with its signers, events, downloads and lists
+    /// Reference: <https://developers.clicksign.com/docs/visualizar-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = client
+    ///       .get_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn get_document(&self, key: &DocumentKey) -> Result<Document, Error> {
+        let url = self.build_url(&format!("documents/{}", key));
+        let request = self.client.get(url);
 
-        let result: HashMap<String, Document> =
-            serde_json::from_str(&self.handler(resp).await.unwrap())?;
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// List the documents of the account, one page at a time
+    /// Reference: <https://developers.clicksign.com/docs/listar-documentos>
+    ///
+    /// # Arguments
+    /// * page (u32): Number of the page, starting at 1
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let page = client.list_documents(1).await.unwrap();
+    ///   println!("{:?}", page.page_infos.next_page);
+    /// };
+    /// ```
+    pub async fn list_documents(
+        &self,
+        page: u32,
+    ) -> Result<DocumentsPage, Error> {
+        let url = self.build_url("documents");
+        let request = self
+            .client
+            .get(url)
+            .query(&[("page", page)]);
+        let result: DocumentsPage = decode(&self.send(request).await?)?;
 
         Ok(result)
     }
 
-    /// Create a new signer
-    /// Reference: <https://developers.clicksign.com/docs/criar-signatario>
+    /// Stream every document of the account, across all pages.
+    /// After the first page is fetched, up to `prefetch` pages are requested concurrently,
+    /// always yielding the documents in the listing order.
     ///
     /// # Arguments
-    /// * request_body (&str): A json-like string with the data of new signer
+    /// * filter (DocumentFilter): Filters applied to the yielded documents
+    /// * prefetch (usize): Maximum number of pages requested at the same time
     ///
     /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::documents::DocumentFilter;
+    ///   use clicksign::models::enums::DocumentStatus;
+    ///   use futures::TryStreamExt;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let filter = DocumentFilter {
+    ///       status: Some(DocumentStatus::Closed),
+    ///       folder: Some("/Contratos".to_string()),
+    ///       ..Default::default()
+    ///   };
+    ///   let documents: Vec<_> = client
+    ///       .documents_stream(filter, 4)
+    ///       .try_collect()
+    ///       .await
+    ///       .unwrap();
+    /// };
     /// ```
+    pub fn documents_stream(
+        &self,
+        filter: DocumentFilter,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<Document, Error>> + '_ {
+        let pages = stream::once(self.list_documents(1))
+            .map(move |first| match first {
+                Ok(first) => {
+                    let last_page = first.page_infos.last_page.unwrap_or(1);
+                    let next_pages = stream::iter(2..=last_page)
+                        .map(move |page| self.list_documents(page))
+                        .buffered(prefetch.max(1));
+                    stream::once(future::ready(Ok(first)))
+                        .chain(next_pages)
+                        .left_stream()
+                }
+                Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+            })
+            .flatten();
+
+        pages
+            .map(|page| match page {
+                Ok(page) => stream::iter(page.documents.into_iter().map(Ok)).left_stream(),
+                Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+            })
+            .flatten()
+            .try_filter(move |document| future::ready(filter.matches(document)))
+    }
+
+    /// Configure an existing document. Clicksign refuses changes in finished or canceled documents.
+    /// Reference: <https://developers.clicksign.com/docs/configurar-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    /// * configuration (DocumentConfiguration): The settings to be changed
+    ///
+    /// # Example
+    /// ```no_run
     /// async {
-    ///   use std::collections::HashMap;
     ///   use clicksign::client::Client;
-    ///   use clicksign::models::signers::Signer;
+    ///   use clicksign::models::documents::DocumentConfiguration;
     ///
     ///   let client = Client::new(
     ///      "some_access_token",
     ///      Some("https://api.example.com/"),
     ///   );
-    ///   let signer_body = r#"
-    ///      {
-    ///          "signer": {
-    ///              "email": "fulano@example.com",
-    ///              "phone_number": "11999999999",
-    ///              "auths": [
-    ///                "email"
-    ///              ],
-    ///              "name": "Marcos Zumba",
-    ///              "documentation": "123.321.123-40",
-    ///              "birthday": "1983-03-31",
-    ///              "has_documentation": true,
-    ///              "selfie_enabled": true,
-    ///              "handwritten_enabled": true,
-    ///              "official_document_enabled": true,
-    ///              "liveness_enabled": true
-    ///          }
-    ///      }
-    ///   "#;
-    ///   let value: HashMap<String, Signer> = serde_json::from_str(signer_body).unwrap();
-    ///   let signer = client.create_signer(value).await.unwrap();
+    ///   let configuration = DocumentConfiguration {
+    ///       deadline_at: Some("2021-12-31T14:30:59-03:00".parse().unwrap()),
+    ///       auto_close: Some(false),
+    ///       ..Default::default()
+    ///   };
+    ///   let document = client
+    ///       .configure_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(), configuration)
+    ///       .await
+    ///       .unwrap();
     /// };
     /// ```
-    pub async fn create_signer(
+    pub async fn configure_document(
         &self,
-        request_body: HashMap<String, Signer>,
-    ) -> Result<HashMap<String, Signer>, Box<dyn std::error::Error>> {
-        // let value: HashMap<String, Signer> = serde_json::from_str(request_body)?;
+        key: &DocumentKey,
+        configuration: DocumentConfiguration,
+    ) -> Result<Document, Error> {
+        let request_body = DocumentEnvelope {
+            document: configuration,
+        };
+
+        let url = self.build_url(&format!("documents/{}", key));
+        let request = self
+            .client
+            .patch(url)
+            .json(&request_body)
+            .header("Content-Type", "application/json");
+
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Finish a document before all the signers sign it
+    /// Reference: <https://developers.clicksign.com/docs/finalizar-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = client
+    ///       .finish_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn finish_document(&self, key: &DocumentKey) -> Result<Document, Error> {
+        let url = self.build_url(&format!("documents/{}/finish", key));
+        let request = self.client.patch(url);
+
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Cancel a running document
+    /// Reference: <https://developers.clicksign.com/docs/cancelar-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = client
+    ///       .cancel_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn cancel_document(&self, key: &DocumentKey) -> Result<Document, Error> {
+        let url = self.build_url(&format!("documents/{}/cancel", key));
+        let request = self.client.patch(url);
+
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Duplicate a document, with its signers, into a new running document
+    /// Reference: <https://developers.clicksign.com/docs/duplicar-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let document = client
+    ///       .duplicate_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn duplicate_document(&self, key: &DocumentKey) -> Result<Document, Error> {
+        let url = self.build_url(&format!("documents/{}/duplicate", key));
+        let request = self.client.post(url);
+
+        let result: DocumentEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.document)
+    }
+
+    /// Delete a document. Clicksign only allows deleting documents that are not running.
+    /// Reference: <https://developers.clicksign.com/docs/excluir-documento>
+    ///
+    /// # Arguments
+    /// * key (&DocumentKey): Unique key of the document within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   client
+    ///       .delete_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn delete_document(&self, key: &DocumentKey) -> Result<(), Error> {
+        let url = self.build_url(&format!("documents/{}", key));
+        let request = self.client.delete(url);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Create a new signer, after checking its fields with `NewSigner::validate`.
+    /// The phone number and CPF are sent as returned by `NewSigner::normalized`.
+    /// Reference: <https://developers.clicksign.com/docs/criar-signatario>
+    ///
+    /// # Arguments
+    /// * signer (NewSigner): The data of the new signer
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::signers::NewSigner;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+    ///       .phone_number("11999999999")
+    ///       .documentation("123.321.123-40")
+    ///       .birthday("1983-03-31".parse().unwrap())
+    ///       .selfie_enabled(true);
+    ///   let signer = client.create_signer(signer).await.unwrap();
+    /// };
+    /// ```
+    pub async fn create_signer(&self, signer: NewSigner) -> Result<SignerResponse, Error> {
+        signer.validate()?;
         let url = self.build_url("signers");
-        let resp = self
+        let request_body = SignerEnvelope {
+            signer: signer.normalized(),
+        };
+        let request = self
             .client
             .post(url)
             .json(&request_body)
-            .header("Content-Type", "application/json")
-            .send()
-            .await?;
-        let result: HashMap<String, Signer> =
-            serde_json::from_str(&self.handler(resp).await.unwrap())?;
+            .header("Content-Type", "application/json");
 
-        Ok(result)
+        let result: SignerEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.signer)
+    }
+
+    /// View a signer
+    /// Reference: <https://developers.clicksign.com/docs/visualizar-signatario>
+    ///
+    /// # Arguments
+    /// * key (&SignerKey): Signer's unique key within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let signer = client
+    ///       .get_signer(&"79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn get_signer(&self, key: &SignerKey) -> Result<SignerResponse, Error> {
+        let url = self.build_url(&format!("signers/{}", key));
+        let request = self.client.get(url);
+
+        let result: SignerEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.signer)
+    }
+
+    /// Delete a signer
+    /// Reference: <https://developers.clicksign.com/docs/excluir-signatario>
+    ///
+    /// # Arguments
+    /// * key (&SignerKey): Signer's unique key within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   client
+    ///       .delete_signer(&"79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn delete_signer(&self, key: &SignerKey) -> Result<(), Error> {
+        let url = self.build_url(&format!("signers/{}", key));
+        let request = self.client.delete(url);
+        self.send(request).await?;
+
+        Ok(())
     }
 
     /// Adding a signer to document
     /// Reference: <https://developers.clicksign.com/docs/adicionar-signatario-a-documento>
     ///
     /// # Arguments
-    /// * request_body (&str): A json-like string with the data of the signer and the document key
+    /// * list (SignerToDocument): The keys of the signer and the document, with how the signer signs it
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// async {
     ///   use clicksign::client::Client;
-    ///   use std::collections::HashMap;
     ///   use clicksign::models::signers::SignerToDocument;
+    ///   use clicksign::models::enums::SignAs;
     ///
     ///   let client = Client::new(
     ///      "some_access_token",
     ///      Some("https://api.example.com/"),
     ///   );
-    ///   let request_body = r#"
-    ///      {
-    ///        "list": {
-    ///          "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
-    ///          "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
-    ///          "sign_as": "sign",
-    ///          "message": "Por favor, assine o documento para completar o seu cadastro."
-    ///        }
-    ///      }
-    ///   "#;
-    ///   let value: HashMap<String, SignerToDocument> =
-    ///         serde_json::from_str(request_body).unwrap();
-    ///   let result = client.add_signer_to_document(value).await.unwrap();
+    ///   let list = SignerToDocument {
+    ///       key: None,
+    ///       request_signature_key: None,
+    ///       document_key: "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+    ///       signer_key: "79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap(),
+    ///       sign_as: SignAs::Sign,
+    ///       created_at: None,
+    ///       updated_at: None,
+    ///       url: None,
+    ///       group: None,
+    ///       message: Some("Por favor, assine o documento para completar o seu cadastro.".to_string()),
+    ///   };
+    ///   let list = client.add_signer_to_document(list).await.unwrap();
     /// };
     /// ```
     pub async fn add_signer_to_document(
         &self,
-        request_body: HashMap<String, SignerToDocument>,
-    ) -> Result<HashMap<String, SignerToDocument>, Box<dyn std::error::Error>> {
+        list: SignerToDocument,
+    ) -> Result<SignerToDocument, Error> {
         let url = self.build_url("lists");
-        let resp = self
+        let request_body = ListEnvelope { list };
+        let request = self
             .client
             .post(url)
             .json(&request_body)
-            .header("Content-Type", "application/json")
-            .send()
-            .await?;
-        let result: HashMap<String, SignerToDocument> =
-            serde_json::from_str(&self.handler(resp).await.unwrap())?;
+            .header("Content-Type", "application/json");
 
-        Ok(result)
+        let result: ListEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.list)
     }
 
-    /// Sending email notification to signer
-    /// Reference: <https://developers.clicksign.com/docs/adicionar-signatario-a-documento>
+    /// Removing a signer from document
+    /// Reference: <https://developers.clicksign.com/docs/remover-signatario-de-documento>
     ///
     /// # Arguments
-    /// * request_body (&str): A json-like string with the request body
+    /// * list_key (&ListKey): The `key` of the `SignerToDocument` returned by `add_signer_to_document`
     ///
     /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::signers::SignerToDocument;
+    ///   use clicksign::models::enums::SignAs;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let list = SignerToDocument {
+    ///       key: None,
+    ///       request_signature_key: None,
+    ///       document_key: "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+    ///       signer_key: "79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap(),
+    ///       sign_as: SignAs::Sign,
+    ///       created_at: None,
+    ///       updated_at: None,
+    ///       url: None,
+    ///       group: None,
+    ///       message: None,
+    ///   };
+    ///   let list = client.add_signer_to_document(list).await.unwrap();
+    ///   let list_key = list.key.as_ref().unwrap();
+    ///   client.remove_signer_from_document(list_key).await.unwrap();
+    /// };
     /// ```
+    pub async fn remove_signer_from_document(
+        &self,
+        list_key: &ListKey,
+    ) -> Result<(), Error> {
+        let url = self.build_url(&format!("lists/{}", list_key));
+        let request = self.client.delete(url);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Create a batch, so a signer can sign many documents at once
+    /// Reference: <https://developers.clicksign.com/docs/criar-lote>
+    ///
+    /// # Arguments
+    /// * signer_key (&SignerKey): Signer's unique key within Clicksign
+    /// * document_keys (&[DocumentKey]): Unique keys of the documents, which the signer must already be added to
+    /// * summary (bool): Whether the signer signs all the documents at once, from a summary page
+    ///
+    /// # Example
+    /// ```no_run
     /// async {
     ///   use clicksign::client::Client;
     ///
@@ -275,29 +1209,274 @@ impl Client {
     ///      "some_access_token",
     ///      Some("https://api.example.com/"),
     ///   );
-    ///   let request_body = r#"
-    ///      {
-    ///        "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
-    ///        "message": "Prezado, seu documento já está disponível para assinatura",
-    ///        "url": "https://www.example.com/abc"
-    ///      }
-    ///   "#;
-    ///   let result = client.request_signing_by_email(request_body).await.unwrap();
+    ///   let batch = client
+    ///       .create_batch(
+    ///           &"79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap(),
+    ///           &[
+    ///               "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+    ///               "ad4a9ab1-cc5f-4a5e-9cd5-9a0a9e2c8e4b".parse().unwrap(),
+    ///           ],
+    ///           true,
+    ///       )
+    ///       .await
+    ///       .unwrap();
+    ///   println!("{}", batch.url.unwrap());
+    /// };
+    /// ```
+    pub async fn create_batch(
+        &self,
+        signer_key: &SignerKey,
+        document_keys: &[DocumentKey],
+        summary: bool,
+    ) -> Result<Batch, Error> {
+        let batch = Batch {
+            key: None,
+            signer_key: signer_key.clone(),
+            document_keys: document_keys.to_vec(),
+            summary,
+            created_at: None,
+            updated_at: None,
+            url: None,
+        };
+        let request_body = BatchEnvelope { batch };
+
+        let url = self.build_url("batches");
+        let request = self
+            .client
+            .post(url)
+            .json(&request_body)
+            .header("Content-Type", "application/json");
+
+        let result: BatchEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.batch)
+    }
+
+    /// View a batch
+    /// Reference: <https://developers.clicksign.com/docs/criar-lote>
+    ///
+    /// # Arguments
+    /// * key (&BatchKey): Unique key of the batch within Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let batch = client
+    ///       .get_batch(&"3b7e8a2c-1a8f-4f7c-9a55-0b1f0c2d7e11".parse().unwrap())
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn get_batch(&self, key: &BatchKey) -> Result<Batch, Error> {
+        let url = self.build_url(&format!("batches/{}", key));
+        let request = self.client.get(url);
+
+        let result: BatchEnvelope = decode(&self.send(request).await?)?;
+        Ok(result.batch)
+    }
+
+    /// Given an endpoint and a notification, sends it and checks it was accepted by clicksign
+    async fn notify(
+        &self,
+        endpoint: &str,
+        channel: NotificationChannel,
+        notification: &Notification,
+    ) -> Result<NotificationResult, Error> {
+        let url = self.build_url(endpoint);
+        let request = self
+            .client
+            .post(url)
+            .json(notification)
+            .header("Content-Type", "application/json");
+        self.send(request).await?;
+
+        Ok(NotificationResult {
+            channel,
+            request_signature_key: notification.request_signature_key.clone(),
+        })
+    }
+
+    /// Sending email notification to signer
+    /// Reference: <https://developers.clicksign.com/docs/solicitar-assinatura-por-email>
+    ///
+    /// # Arguments
+    /// * notification (&Notification): The `request_signature_key` of the signer, with an optional message and url
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::notifications::Notification;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let notification = Notification {
+    ///       request_signature_key: "0d5a9615-2bb8-3a23-6584-33ff436bb990".parse().unwrap(),
+    ///       message: Some("Prezado, seu documento já está disponível para assinatura".to_string()),
+    ///       url: Some("https://www.example.com/abc".to_string()),
+    ///   };
+    ///   let result = client.request_signing_by_email(&notification).await.unwrap();
     /// };
     /// ```
     pub async fn request_signing_by_email(
         &self,
-        request_body: &str,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let value: HashMap<String, String> = serde_json::from_str(request_body)?;
-        let url = self.build_url("notifications");
-        let _ = self
+        notification: &Notification,
+    ) -> Result<NotificationResult, Error> {
+        self.notify("notifications", NotificationChannel::Email, notification)
+            .await
+    }
+
+    /// Sending Whatsapp notification to signer
+    /// Reference: <https://developers.clicksign.com/docs/solicitar-assinatura-por-whatsapp>
+    ///
+    /// # Arguments
+    /// * list (&SignerToDocument): The signer added to the document, with its `request_signature_key`
+    /// * signer (&SignerResponse): The signer, whose `phone_number` must be a valid mobile number.
+    ///   Otherwise, returns `Error::InvalidFields` without calling Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::enums::{AuthMethod, SignAs};
+    ///   use clicksign::models::signers::{NewSigner, SignerToDocument};
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+    ///       .phone_number("11999999999")
+    ///       .auths(&[AuthMethod::Whatsapp]);
+    ///   let signer = client.create_signer(signer).await.unwrap();
+    ///   let list = SignerToDocument {
+    ///       key: None,
+    ///       request_signature_key: None,
+    ///       document_key: "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+    ///       signer_key: signer.key.clone().unwrap(),
+    ///       sign_as: SignAs::Sign,
+    ///       created_at: None,
+    ///       updated_at: None,
+    ///       url: None,
+    ///       group: None,
+    ///       message: None,
+    ///   };
+    ///   let list = client.add_signer_to_document(list).await.unwrap();
+    ///   client
+    ///       .request_signing_by_whatsapp(&list, &signer)
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn request_signing_by_whatsapp(
+        &self,
+        list: &SignerToDocument,
+        signer: &SignerResponse,
+    ) -> Result<NotificationResult, Error> {
+        let notification = phone_notification(list, signer)?;
+        self.notify("notify_by_whatsapp", NotificationChannel::Whatsapp, &notification)
+            .await
+    }
+
+    /// Sending SMS notification to signer
+    /// Reference: <https://developers.clicksign.com/docs/solicitar-assinatura-por-sms>
+    ///
+    /// # Arguments
+    /// * list (&SignerToDocument): The signer added to the document, with its `request_signature_key`
+    /// * signer (&SignerResponse): The signer, whose `phone_number` must be a valid mobile number.
+    ///   Otherwise, returns `Error::InvalidFields` without calling Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///   use clicksign::models::enums::{AuthMethod, SignAs};
+    ///   use clicksign::models::signers::{NewSigner, SignerToDocument};
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+    ///       .phone_number("11999999999")
+    ///       .auths(&[AuthMethod::Sms]);
+    ///   let signer = client.create_signer(signer).await.unwrap();
+    ///   let list = SignerToDocument {
+    ///       key: None,
+    ///       request_signature_key: None,
+    ///       document_key: "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+    ///       signer_key: signer.key.clone().unwrap(),
+    ///       sign_as: SignAs::Sign,
+    ///       created_at: None,
+    ///       updated_at: None,
+    ///       url: None,
+    ///       group: None,
+    ///       message: None,
+    ///   };
+    ///   let list = client.add_signer_to_document(list).await.unwrap();
+    ///   client
+    ///       .request_signing_by_sms(&list, &signer)
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn request_signing_by_sms(
+        &self,
+        list: &SignerToDocument,
+        signer: &SignerResponse,
+    ) -> Result<NotificationResult, Error> {
+        let notification = phone_notification(list, signer)?;
+        self.notify("notify_by_sms", NotificationChannel::Sms, &notification)
+            .await
+    }
+
+    /// Sign a document via API, on behalf of a signer with the "api" authentication
+    /// Reference: <https://developers.clicksign.com/docs/assinatura-via-api>
+    ///
+    /// # Arguments
+    /// * request_signature_key (&RequestSignatureKey): The `request_signature_key` of the signer in the document
+    /// * secret (&str): The signer's API secret, provided by Clicksign
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::client::Client;
+    ///
+    ///   let client = Client::new(
+    ///      "some_access_token",
+    ///      Some("https://api.example.com/"),
+    ///   );
+    ///   client
+    ///       .sign_via_api(&"0d5a9615-2bb8-3a23-6584-33ff436bb990".parse().unwrap(), "some_signer_secret")
+    ///       .await
+    ///       .unwrap();
+    /// };
+    /// ```
+    pub async fn sign_via_api(
+        &self,
+        request_signature_key: &RequestSignatureKey,
+        secret: &str,
+    ) -> Result<(), Error> {
+        let request_body = serde_json::json!({
+            "request_signature_key": request_signature_key,
+            "secret_hmac_sha256": sign_secret_hmac(request_signature_key.as_str(), secret),
+        });
+
+        let url = self.build_url("sign");
+        let request = self
             .client
             .post(url)
-            .json(&value)
-            .header("Content-Type", "application/json")
-            .send()
-            .await?;
+            .json(&request_body)
+            .header("Content-Type", "application/json");
+        self.send(request).await?;
+
         Ok(())
     }
 }
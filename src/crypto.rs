@@ -0,0 +1,62 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Computes the HMAC-SHA256 of a message, returning it as a lowercase hex string
+///
+/// # Arguments
+/// * key (&[u8]): The secret key
+/// * message (&[u8]): The message to be authenticated
+///
+/// # Example
+/// ```
+/// use clicksign::crypto::hmac_sha256_hex;
+///
+/// let hmac = hmac_sha256_hex(b"key", b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+///     hmac
+/// );
+/// ```
+pub fn hmac_sha256_hex(key: &[u8], message: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(message);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Computes the `secret_hmac_sha256` field expected by the sign endpoint: the HMAC-SHA256
+/// of the request signature key, using the signer's API secret as key
+///
+/// # Arguments
+/// * request_signature_key (&str): The `request_signature_key` of the signer in the document
+/// * secret (&str): The signer's API secret
+pub fn sign_secret_hmac(request_signature_key: &str, secret: &str) -> String {
+    hmac_sha256_hex(secret.as_bytes(), request_signature_key.as_bytes())
+}
+
+/// Checks, in constant time, whether a lowercase or uppercase hex string is the
+/// HMAC-SHA256 of a message
+///
+/// # Arguments
+/// * key (&[u8]): The secret key
+/// * message (&[u8]): The authenticated message
+/// * hex_hmac (&str): The HMAC to be checked, as a hex string
+///
+/// # Example
+/// ```
+/// use clicksign::crypto::verify_hmac_sha256_hex;
+///
+/// assert!(verify_hmac_sha256_hex(
+///     b"key",
+///     b"The quick brown fox jumps over the lazy dog",
+///     "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+/// ));
+/// ```
+pub fn verify_hmac_sha256_hex(key: &[u8], message: &[u8], hex_hmac: &str) -> bool {
+    let expected = match hex::decode(hex_hmac) {
+        Ok(expected) => expected,
+        Err(_) => return false,
+    };
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(message);
+    mac.verify_slice(&expected).is_ok()
+}
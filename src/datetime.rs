@@ -0,0 +1,294 @@
+//! Dates and datetimes keep the text sent by Clicksign, so the model fields have the same
+//! types whatever cargo features are enabled. The optional features only add conversions:
+//! * `chrono`: from and to `chrono::DateTime` and `chrono::NaiveDate`;
+//! * `time`: from and to `time::OffsetDateTime` and `time::Date`.
+//!
+//! Both features can be enabled together.
+
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "chrono", feature = "time"))]
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Datetime with the offset sent by Clicksign, e.g. `2021-12-31T14:30:59.000-03:00`.
+/// Datetimes are validated when parsed, but deserialized as sent by Clicksign, so a datetime
+/// in an unexpected format never breaks a response.
+///
+/// # Example
+/// ```
+/// use clicksign::datetime::DateTime;
+///
+/// let deadline: DateTime = "2021-12-31T14:30:59.000-03:00".parse().unwrap();
+/// assert_eq!("2021-12-31T14:30:59.000-03:00", deadline.as_str());
+/// assert!("2021-12-31".parse::<DateTime>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DateTime(String);
+
+/// Date without time, e.g. `1983-03-31`.
+/// Dates are validated when parsed, but deserialized as sent by Clicksign.
+///
+/// # Example
+/// ```
+/// use clicksign::datetime::Date;
+///
+/// let birthday: Date = "1983-03-31".parse().unwrap();
+/// assert_eq!("1983-03-31", birthday.as_str());
+/// assert!("1983-02-30".parse::<Date>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Date(String);
+
+/// Given a text, returns its value if it only has ASCII digits
+fn number(value: &str) -> Option<i64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Given a date like `1983-03-31`, returns its year, month and day, if it exists
+pub(crate) fn civil_date(date: &str) -> Option<(i64, u32, u32)> {
+    if date.len() != 10 || date.as_bytes()[4] != b'-' || date.as_bytes()[7] != b'-' {
+        return None;
+    }
+    let year = number(date.get(..4)?)?;
+    let month = number(date.get(5..7)?)? as u32;
+    let day = number(date.get(8..)?)? as u32;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if day == 0 || day > days_in_month {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Given a civil date, returns the number of days since 1970-01-01, see
+/// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Given an RFC 3339 datetime, e.g. `2021-12-31T14:30:59.000-03:00`, returns its unix
+/// timestamp in seconds and the nanoseconds
+fn unix_timestamp(value: &str) -> Option<(i64, u32)> {
+    let (year, month, day) = civil_date(value.get(..10)?)?;
+    let time = value.get(10..)?;
+    let bytes = time.as_bytes();
+    if bytes.len() < 9 || !matches!(bytes[0], b'T' | b't') || bytes[3] != b':' || bytes[6] != b':' {
+        return None;
+    }
+    let hour = number(time.get(1..3)?)?;
+    let minute = number(time.get(4..6)?)?;
+    let second = number(time.get(7..9)?)?;
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let mut rest = time.get(9..)?;
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 || len > 9 {
+            return None;
+        }
+        nanos = number(&fraction[..len])? as u32 * 10u32.pow(9 - len as u32);
+        rest = &fraction[len..];
+    }
+    let offset = match rest.as_bytes() {
+        [b'Z'] | [b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let hours = number(&rest[1..3])?;
+            let minutes = number(&rest[4..])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = hours * 3_600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second;
+    Some((seconds - offset, nanos))
+}
+
+impl DateTime {
+    /// Given an RFC 3339 datetime, e.g. `2021-12-31T14:30:59.000-03:00`, returns it,
+    /// failing when it is not a valid datetime
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        match unix_timestamp(value) {
+            Some(_) => Ok(DateTime(value.to_string())),
+            None => Err(Error::InvalidInput(format!("Invalid datetime: {}", value))),
+        }
+    }
+
+    /// Returns the datetime as sent by Clicksign
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the unix timestamp in seconds and the nanoseconds of the datetime, to compare
+    /// datetimes in different offsets. Returns None if the datetime is invalid.
+    pub(crate) fn instant(&self) -> Option<(i64, u32)> {
+        unix_timestamp(&self.0)
+    }
+}
+
+impl Date {
+    /// Given a date like `1983-03-31`, returns it, failing when it is not a valid date
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        match civil_date(value) {
+            Some(_) => Ok(Date(value.to_string())),
+            None => Err(Error::InvalidInput(format!("Invalid date: {}", value))),
+        }
+    }
+
+    /// Returns the date as sent by Clicksign
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        DateTime::parse(value)
+    }
+}
+
+impl FromStr for Date {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Date::parse(value)
+    }
+}
+
+impl AsRef<str> for DateTime {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Date {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Given a datetime, returns it in the format used by Clicksign, with milliseconds
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime
+where
+    Tz::Offset: fmt::Display,
+{
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        DateTime(datetime.to_rfc3339_opts(chrono::SecondsFormat::Millis, false))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = Error;
+
+    fn try_from(datetime: &DateTime) -> Result<Self, Self::Error> {
+        chrono::DateTime::parse_from_rfc3339(&datetime.0)
+            .map_err(|e| Error::InvalidInput(format!("Invalid datetime {}: {}", datetime, e)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(date: chrono::NaiveDate) -> Self {
+        Date(date.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&Date> for chrono::NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: &Date) -> Result<Self, Self::Error> {
+        chrono::NaiveDate::parse_from_str(&date.0, "%Y-%m-%d")
+            .map_err(|e| Error::InvalidInput(format!("Invalid date {}: {}", date, e)))
+    }
+}
+
+/// Given a datetime, returns it in the format used by Clicksign, with milliseconds
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for DateTime {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        DateTime(
+            datetime
+                .format(time::macros::format_description!(
+                    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3][offset_hour sign:mandatory]:[offset_minute]"
+                ))
+                .expect("datetimes can always be formatted"),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<&DateTime> for time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: &DateTime) -> Result<Self, Self::Error> {
+        time::OffsetDateTime::parse(&datetime.0, &time::format_description::well_known::Rfc3339)
+            .map_err(|e| Error::InvalidInput(format!("Invalid datetime {}: {}", datetime, e)))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for Date {
+    fn from(date: time::Date) -> Self {
+        Date(
+            date.format(time::macros::format_description!("[year]-[month]-[day]"))
+                .expect("dates can always be formatted"),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<&Date> for time::Date {
+    type Error = Error;
+
+    fn try_from(date: &Date) -> Result<Self, Self::Error> {
+        time::Date::parse(
+            &date.0,
+            time::macros::format_description!("[year]-[month]-[day]"),
+        )
+        .map_err(|e| Error::InvalidInput(format!("Invalid date {}: {}", date, e)))
+    }
+}
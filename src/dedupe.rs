@@ -0,0 +1,263 @@
+use crate::error::Error;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+
+/// How long a claimed key stays pending by default, before it can be claimed again.
+/// It bounds how long an event is blocked when the process dies while handling it.
+pub const DEFAULT_LEASE: Duration = Duration::from_secs(60);
+
+/// Result of claiming the key of a webhook event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Claim {
+    /// The key was free and is now pending, so the caller must handle the event
+    Acquired,
+    /// The event is being handled by another delivery, whose lease didn't expire yet
+    Pending,
+    /// The event was already handled
+    Completed,
+}
+
+/// Stores the keys of the webhook events being handled or already handled, so each
+/// event is handled once even when clicksign sends it again.
+/// A claimed key is pending until it's completed or released, or until its lease expires.
+#[async_trait]
+pub trait DedupeStore: Send + Sync {
+    /// Marks the key as pending, unless it's already pending or completed
+    async fn claim(&self, key: &str) -> Result<Claim, Error>;
+    /// Marks the pending key as completed, after the event was handled
+    async fn complete(&self, key: &str) -> Result<(), Error>;
+    /// Unmarks the key, so the event can be handled again (e.g. after a failure)
+    async fn release(&self, key: &str) -> Result<(), Error>;
+}
+
+/// State of a key kept by the stores
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Pending,
+    Completed,
+}
+
+impl State {
+    fn claim(self) -> Claim {
+        match self {
+            State::Pending => Claim::Pending,
+            State::Completed => Claim::Completed,
+        }
+    }
+}
+
+/// In-memory `DedupeStore`, forgetting the completed keys after a time to live
+#[derive(Debug)]
+pub struct MemoryDedupeStore {
+    ttl: Duration,
+    lease: Duration,
+    keys: Mutex<HashMap<String, (State, Instant)>>,
+}
+
+impl MemoryDedupeStore {
+    /// Given a time to live, creates an empty store, with the `DEFAULT_LEASE`
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::dedupe::MemoryDedupeStore;
+    /// use std::time::Duration;
+    ///
+    /// let store = MemoryDedupeStore::new(Duration::from_secs(7 * 24 * 60 * 60));
+    /// ```
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            lease: DEFAULT_LEASE,
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Given how long a claimed key stays pending, returns the store using it
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+}
+
+#[async_trait]
+impl DedupeStore for MemoryDedupeStore {
+    async fn claim(&self, key: &str) -> Result<Claim, Error> {
+        let now = Instant::now();
+        let mut keys = self.keys.lock().unwrap();
+        keys.retain(|_, (_, expires_at)| *expires_at > now);
+        if let Some((state, _)) = keys.get(key) {
+            return Ok(state.claim());
+        }
+        keys.insert(key.to_string(), (State::Pending, now + self.lease));
+        Ok(Claim::Acquired)
+    }
+
+    async fn complete(&self, key: &str) -> Result<(), Error> {
+        let expires_at = Instant::now() + self.ttl;
+        self.keys
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (State::Completed, expires_at));
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), Error> {
+        self.keys.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+/// File-backed `DedupeStore`, keeping the keys across restarts.
+/// Each line of the file holds the unix timestamp in which the key expires, its state
+/// (`pending` or `completed`) and the key, separated by tabs. The last line of a key wins.
+#[derive(Debug)]
+pub struct FileDedupeStore {
+    path: PathBuf,
+    ttl: Duration,
+    lease: Duration,
+    keys: tokio::sync::Mutex<HashMap<String, (State, u64)>>,
+}
+
+/// Returns the current unix timestamp, in seconds
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Given a line of the file, returns its key, state and expiration timestamp
+fn parse_line(line: &str) -> Option<(String, (State, u64))> {
+    let mut fields = line.splitn(3, '\t');
+    let expires_at: u64 = fields.next()?.parse().ok()?;
+    let state = match fields.next()? {
+        "pending" => State::Pending,
+        "completed" => State::Completed,
+        _ => return None,
+    };
+    let key = fields.next()?;
+    Some((key.to_string(), (state, expires_at)))
+}
+
+/// Given a key, its state and expiration timestamp, returns its line in the file
+fn format_line(key: &str, state: State, expires_at: u64) -> String {
+    let state = match state {
+        State::Pending => "pending",
+        State::Completed => "completed",
+    };
+    format!("{}\t{}\t{}\n", expires_at, state, key)
+}
+
+impl FileDedupeStore {
+    /// Given a file path and a time to live, loads the keys stored in the file,
+    /// dropping the expired ones. The file is created if it doesn't exist.
+    /// The store uses the `DEFAULT_LEASE`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// async {
+    ///   use clicksign::dedupe::FileDedupeStore;
+    ///   use std::time::Duration;
+    ///
+    ///   let store = FileDedupeStore::open("webhooks.dedupe", Duration::from_secs(7 * 24 * 60 * 60))
+    ///       .await
+    ///       .unwrap()
+    ///       .with_lease(Duration::from_secs(5 * 60));
+    /// };
+    /// ```
+    pub async fn open<P: AsRef<Path>>(path: P, ttl: Duration) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let content = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let now = unix_now();
+        let mut keys: HashMap<String, (State, u64)> = content
+            .lines()
+            .filter_map(parse_line)
+            .collect();
+        keys.retain(|_, (_, expires_at)| *expires_at > now);
+        let store = Self {
+            path,
+            ttl,
+            lease: DEFAULT_LEASE,
+            keys: tokio::sync::Mutex::new(keys),
+        };
+        store.rewrite(&*store.keys.lock().await).await?;
+        Ok(store)
+    }
+
+    /// Given how long a claimed key stays pending, returns the store using it
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Given the current keys, rewrites the whole file.
+    /// The keys are written to a temporary file in the same directory, which then replaces
+    /// the file, so a crash while rewriting never loses the keys already stored.
+    async fn rewrite(&self, keys: &HashMap<String, (State, u64)>) -> Result<(), Error> {
+        let content: String = keys
+            .iter()
+            .map(|(key, (state, expires_at))| format_line(key, *state, *expires_at))
+            .collect();
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".tmp");
+        let temp_path = self.path.with_file_name(file_name);
+        let mut file = tokio::fs::File::create(&temp_path).await?;
+        file.write_all(content.as_bytes()).await?;
+        file.sync_all().await?;
+        tokio::fs::rename(&temp_path, &self.path).await?;
+        Ok(())
+    }
+
+    /// Given a key, its state and expiration timestamp, appends it to the file
+    async fn append(&self, key: &str, state: State, expires_at: u64) -> Result<(), Error> {
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(format_line(key, state, expires_at).as_bytes())
+            .await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DedupeStore for FileDedupeStore {
+    async fn claim(&self, key: &str) -> Result<Claim, Error> {
+        let now = unix_now();
+        let mut keys = self.keys.lock().await;
+        match keys.get(key) {
+            Some((state, expires_at)) if *expires_at > now => return Ok(state.claim()),
+            _ => {}
+        }
+        let expires_at = now + self.lease.as_secs();
+        self.append(key, State::Pending, expires_at).await?;
+        keys.insert(key.to_string(), (State::Pending, expires_at));
+        Ok(Claim::Acquired)
+    }
+
+    async fn complete(&self, key: &str) -> Result<(), Error> {
+        let mut keys = self.keys.lock().await;
+        let expires_at = unix_now() + self.ttl.as_secs();
+        self.append(key, State::Completed, expires_at).await?;
+        keys.insert(key.to_string(), (State::Completed, expires_at));
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), Error> {
+        let mut keys = self.keys.lock().await;
+        if keys.remove(key).is_some() {
+            self.rewrite(&keys).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+pub use url::Url;
+
+/// Host of the clicksign production environment
+const PRODUCTION_HOST: &str = "https://app.clicksign.com/";
+/// Host of the clicksign sandbox environment
+const SANDBOX_HOST: &str = "https://sandbox.clicksign.com/";
+
+/// Defines the clicksign environment the client sends requests to
+///
+/// # Example
+/// ```
+/// use clicksign::environment::{Environment, Url};
+///
+/// assert_eq!("https://sandbox.clicksign.com/", Environment::Sandbox.host().as_str());
+///
+/// let mock = Environment::Custom(Url::parse("http://localhost:8080").unwrap());
+/// assert_eq!("http://localhost:8080/", mock.host().as_str());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Environment {
+    /// `https://sandbox.clicksign.com`, for tests and integration development
+    Sandbox,
+    /// `https://app.clicksign.com`
+    #[default]
+    Production,
+    /// Any other host, e.g. a proxy or a mock server
+    Custom(Url),
+}
+
+impl Environment {
+    /// Returns the host of the environment
+    pub fn host(&self) -> Url {
+        match self {
+            Environment::Sandbox => Url::parse(SANDBOX_HOST).expect("valid sandbox host"),
+            Environment::Production => Url::parse(PRODUCTION_HOST).expect("valid production host"),
+            Environment::Custom(url) => url.clone(),
+        }
+    }
+}
+
+/// Defines the version of the clicksign API used by the client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ApiVersion {
+    /// API v1, under `/api/v1/`
+    #[default]
+    V1,
+}
+
+impl ApiVersion {
+    /// Returns the path segments of the version, appended to the environment host
+    pub fn segments(&self) -> &'static [&'static str] {
+        match self {
+            ApiVersion::V1 => &["api", "v1"],
+        }
+    }
+}
+
+/// Given an environment and an API version, returns the base URL of the API,
+/// ending with a slash (e.g. `https://app.clicksign.com/api/v1/`).
+/// The version path is not appended again when the host already ends with it.
+pub(crate) fn base_url(environment: &Environment, api_version: ApiVersion) -> Option<Url> {
+    let mut url = environment.host();
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    url.set_query(None);
+    url.set_fragment(None);
+    let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+    let has_version = segments.ends_with(api_version.segments());
+    {
+        let mut path = url.path_segments_mut().ok()?;
+        path.pop_if_empty();
+        if !has_version {
+            path.extend(api_version.segments());
+        }
+        path.push("");
+    }
+    Some(url)
+}
@@ -0,0 +1,224 @@
+use crate::secret::mask_access_token;
+use crate::validation::FieldError;
+use reqwest::StatusCode;
+use std::fmt;
+use std::time::Duration;
+
+/// Errors returned by the clicksign client
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read
+    Transport(reqwest::Error),
+    /// The HTTP client could not be built with the settings of the `ClientBuilder`
+    Builder(reqwest::Error),
+    /// The request did not complete in time
+    Timeout(reqwest::Error),
+    /// The access token is invalid (401)
+    Unauthorized,
+    /// The access token has no permission for the operation (403)
+    Forbidden,
+    /// The resource does not exist (404)
+    NotFound,
+    /// Clicksign refused the request (400 or 422), with the messages from its `errors` field
+    Validation {
+        /// Status code of the response
+        status: StatusCode,
+        /// Messages parsed from the `errors` field of the response body
+        errors: Vec<String>,
+        /// Raw response body
+        body: String,
+    },
+    /// Too many requests were sent to Clicksign (429)
+    RateLimited {
+        /// How long to wait before sending new requests, from the rate limit headers
+        retry_after: Option<Duration>,
+    },
+    /// Clicksign failed to process the request (5xx)
+    Server {
+        /// Status code of the response
+        status: StatusCode,
+        /// Raw response body
+        body: String,
+    },
+    /// Clicksign answered with a status code not expected by the client
+    UnexpectedStatus {
+        /// Status code of the response
+        status: StatusCode,
+        /// Raw response body
+        body: String,
+    },
+    /// The response body could not be deserialized
+    Decode {
+        /// The deserialization error
+        source: serde_json::Error,
+        /// Raw response body
+        body: String,
+    },
+    /// A local file could not be read
+    Io(std::io::Error),
+    /// The given arguments were refused before calling Clicksign
+    InvalidInput(String),
+    /// Fields of a request were refused by the local validation, before calling Clicksign
+    InvalidFields(Vec<FieldError>),
+    /// The `Content-Hmac` header of a webhook does not match its body
+    InvalidSignature,
+    /// The request failed after being retried
+    Retried {
+        /// Number of attempts made, including the first one
+        attempts: u32,
+        /// The error of the last attempt
+        last_error: Box<Error>,
+    },
+}
+
+/// Result type returned by the clicksign client
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Given the status, the `Retry-After` and the body of an unsuccessful response,
+    /// returns the matching error
+    pub(crate) fn from_response(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: String,
+    ) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => Error::Unauthorized,
+            StatusCode::FORBIDDEN => Error::Forbidden,
+            StatusCode::NOT_FOUND => Error::NotFound,
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after },
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Error::Validation {
+                status,
+                errors: parse_errors(&body),
+                body,
+            },
+            status if status.is_server_error() => Error::Server { status, body },
+            status => Error::UnexpectedStatus { status, body },
+        }
+    }
+
+    /// Returns how many attempts were made for the failed request
+    pub fn attempts(&self) -> u32 {
+        match self {
+            Error::Retried { attempts, .. } => *attempts,
+            _ => 1,
+        }
+    }
+
+    /// Returns the error of the last attempt, unwrapping retried errors
+    pub fn last_error(&self) -> &Error {
+        match self {
+            Error::Retried { last_error, .. } => last_error.last_error(),
+            error => error,
+        }
+    }
+
+    /// Given a deserialization error and the body being deserialized, returns a decode error
+    pub(crate) fn decode(source: serde_json::Error, body: &str) -> Self {
+        Error::Decode {
+            source,
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Given a response body like `{"errors": [...]}`, returns the error messages in it.
+/// Messages keyed by field (`{"errors": {"email": ["..."]}}`) are prefixed with the field name.
+fn parse_errors(body: &str) -> Vec<String> {
+    use serde_json::Value;
+
+    fn message(value: &Value) -> String {
+        match value {
+            Value::String(message) => message.clone(),
+            value => value.to_string(),
+        }
+    }
+
+    let value: Value = match serde_json::from_str(body) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+    match value.get("errors") {
+        Some(Value::Array(errors)) => errors.iter().map(message).collect(),
+        Some(Value::Object(errors)) => errors
+            .iter()
+            .flat_map(|(field, messages)| match messages {
+                Value::Array(messages) => messages
+                    .iter()
+                    .map(|m| format!("{}: {}", field, message(m)))
+                    .collect(),
+                messages => vec![format!("{}: {}", field, message(messages))],
+            })
+            .collect(),
+        Some(error) => vec![message(error)],
+        None => Vec::new(),
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "Transport error: {}", e),
+            Error::Builder(e) => write!(f, "Invalid client settings: {}", e),
+            Error::Timeout(e) => write!(f, "Timeout: {}", e),
+            Error::Unauthorized => write!(f, "401 Unauthorized"),
+            Error::Forbidden => write!(f, "403 Forbidden"),
+            Error::NotFound => write!(f, "404 Not Found"),
+            Error::Validation { status, errors, body } if errors.is_empty() => {
+                write!(f, "{}: {}", status, body)
+            }
+            Error::Validation { status, errors, .. } => {
+                write!(f, "{}: {}", status, errors.join("; "))
+            }
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "429 Too Many Requests (retry after {:?})", retry_after),
+            Error::RateLimited { retry_after: None } => write!(f, "429 Too Many Requests"),
+            Error::Server { status, .. } => write!(f, "{}", status),
+            Error::UnexpectedStatus { status, .. } => write!(f, "Received response: {}", status),
+            Error::Decode { source, .. } => write!(f, "Invalid response body: {}", source),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            Error::InvalidFields(errors) => {
+                let errors: Vec<String> = errors.iter().map(FieldError::to_string).collect();
+                write!(f, "Invalid fields: {}", errors.join("; "))
+            }
+            Error::InvalidSignature => write!(f, "Invalid webhook signature"),
+            Error::Retried {
+                attempts,
+                last_error,
+            } => write!(f, "{} (after {} attempts)", last_error, attempts),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) | Error::Builder(e) | Error::Timeout(e) => Some(e),
+            Error::Decode { source, .. } => Some(source),
+            Error::Io(e) => Some(e),
+            Error::Retried { last_error, .. } => Some(last_error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(mut e: reqwest::Error) -> Self {
+        if let Some(url) = e.url_mut() {
+            mask_access_token(url);
+        }
+        if e.is_timeout() {
+            Error::Timeout(e)
+        } else {
+            Error::Transport(e)
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
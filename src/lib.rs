@@ -13,33 +13,41 @@
 //! ---
 //! # Features
 //! ### Documents
-//! - [ ] Create document by upload
+//! - [x] Create document by upload
 //! - [x] Create document by template
-//! - [ ] View document
-//! - [ ] View all documents
-//! - [ ] Configure document
-//! - [ ] Finish document
-//! - [ ] Cancel document
-//! - [ ] Duplicate document
-//! - [ ] Delete document
+//! - [x] View document
+//! - [x] View all documents
+//! - [x] Configure document
+//! - [x] Finish document
+//! - [x] Cancel document
+//! - [x] Duplicate document
+//! - [x] Delete document
 //!
 //! ### Signers
 //! - [x] Create signer
-//! - [ ] View Signer
+//! - [x] View Signer
 //! - [x] Add signer to document
-//! - [ ] Remove signer from document
-//! - [ ] Delete signer
+//! - [x] Remove signer from document
+//! - [x] Delete signer
 //!
 //! ### Batches
-//! - [ ] Create batches
+//! - [x] Create batches
 //!
 //! ### Notifications
 //! - [x] Request signing by email
-//! - [ ] Request signing by Whatsapp
-//! - [ ] Request signing by SMS
+//! - [x] Request signing by Whatsapp
+//! - [x] Request signing by SMS
 //!
 //! ### Sign documents via API
-//! - [ ] Sign documents via API
+//! - [x] Sign documents via API
+//!
+//! # Cargo features
+//! - `webhook-server`: axum router receiving the webhook events, see `webhook_server`
+//! - `chrono`: conversions of dates and datetimes from and to `chrono` types, see `datetime`
+//! - `time`: conversions of dates and datetimes from and to `time` types, see `datetime`
+//!
+//! The features are additive: dates and datetimes always keep the text sent by
+//! Clicksign, so enabling `chrono` or `time` never changes the types of the models.
 //!
 //! # Usage
 //! ## Instantiating a client
@@ -50,38 +58,34 @@
 //!    "c9d91ece-9b3b-4def-abac-25b645cb083c",
 //!    Some("https://api.example.com"),
 //! );
-//! assert_eq!("https://api.example.com", client.host);
-//! assert_eq!("c9d91ece-9b3b-4def-abac-25b645cb083c", client.access_token);
+//! assert_eq!("https://api.example.com/api/v1/", client.base_url().as_str());
+//! assert_eq!("c9d91ece-9b3b-4def-abac-25b645cb083c", client.access_token().expose_secret());
 //! ```
 //! ## Creating a new document for sign
 //! ```no_run
 //! async {
 //!  use clicksign::client::Client;
 //!  use std::collections::HashMap;
-//!  use clicksign::models::documents::Document;
+//!  use clicksign::models::documents::{Document, DocumentTemplate};
 //!
 //!  let client = Client::new(
 //!     "some_access_token",
 //!     Some("https://api.example.com/"),
 //!  );
-//!  let template_body = r#"
-//!     {
-//!       "document": {
-//!         "path": "/Modelos/Teste-123.docx",
-//!         "template": {
-//!           "data": {
-//!             "Company Name": "Clicksign Gestão de Documentos S.A.",
-//!             "Address": "R. Teodoro Sampaio 2767, 10° andar",
-//!             "Phone": "(11) 3145-2570",
-//1             "Website": "https://www.clicksign.com"
-//!           }
-//!         }
-//!       }
-//!     }
-//!  "#;
-//!
-//!  let value: HashMap<String, Document> = serde_json::from_str(template_body).unwrap();
-//!  let document = client.create_document_by_model(value).await.unwrap();
+//!  let mut data = HashMap::new();
+//!  data.insert("Company Name".to_string(), "Clicksign Gestão de Documentos S.A.".to_string());
+//!  data.insert("Address".to_string(), "R. Teodoro Sampaio 2767, 10° andar".to_string());
+//!  data.insert("Phone".to_string(), "(11) 3145-2570".to_string());
+//!  data.insert("Website".to_string(), "https://www.clicksign.com".to_string());
+//!  let document = Document {
+//!     path: "/Modelos/Teste-123.docx".to_string(),
+//!     template: Some(DocumentTemplate {
+//!         key: "e4c6b5b1-3a5c-4a42-9c1e-6f1b0d7c2a10".parse().unwrap(),
+//!         data,
+//!     }),
+//!     ..Default::default()
+//!  };
+//!  let document = client.create_document_by_model(document).await.unwrap();
 //! };
 //! ```
 //! # License
@@ -115,9 +119,32 @@
         unstable_features,
         unused_import_braces, unused_qualifications)]
 
-extern crate error_chain;
-
 /// Implementing a clicksign API client
 pub mod client;
+/// HMAC-SHA256 helpers used to sign documents via API and verify webhooks
+pub mod crypto;
+/// Date and time types, convertible to `chrono` or `time` types when their features are enabled
+pub mod datetime;
+/// Stores for processing each webhook event only once
+pub mod dedupe;
+/// Clicksign environments and API versions
+pub mod environment;
+/// Errors returned by the clicksign client
+pub mod error;
 /// Request/Response models for clicksign API
 pub mod models;
+/// Client-side rate limiting
+pub mod rate_limit;
+/// Retry policy for transient failures
+pub mod retry;
+/// Secret values redacted from debug output
+pub mod secret;
+/// Validation and formatting of Brazilian documents and contacts of signers
+pub mod validation;
+/// Receiving and verifying clicksign webhooks
+pub mod webhooks;
+/// Ready-made axum router for receiving clicksign webhooks
+#[cfg(feature = "webhook-server")]
+pub mod webhook_server;
+
+pub use crate::error::Error;
@@ -0,0 +1,28 @@
+use crate::datetime::DateTime;
+use crate::models::keys::{BatchKey, DocumentKey, SignerKey};
+use serde::{Deserialize, Serialize};
+
+/// This struct defines a request and response body for POST /api/v1/batches endpoint
+/// The optional fields only make sense in the Responses body. Unset fields are not sent.
+/// Check [clicksign docs](https://developers.clicksign.com/docs/criar-lote) for detailed info.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Batch {
+    /// Unique key of the batch within Clicksign (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<BatchKey>,
+    /// Signer's unique key within Clicksign
+    pub signer_key: SignerKey,
+    /// Unique keys of the documents to be signed in the batch
+    pub document_keys: Vec<DocumentKey>,
+    /// Indicates whether the signer signs all the documents at once, from a summary page
+    pub summary: bool,
+    /// Batch creation datetime (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Batch update datetime (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// URL to signing the documents of the batch (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
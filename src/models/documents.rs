@@ -1,3 +1,8 @@
+use crate::datetime::{Date, DateTime};
+use crate::models::enums::{AuthMethod, DocumentStatus, Locale, SignAs};
+use crate::models::events::DocumentEvent;
+use crate::models::keys::{DocumentKey, ListKey, RequestSignatureKey, SignerKey, TemplateKey};
+use crate::models::signers::SignerToDocument;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -5,72 +10,322 @@ use std::collections::HashMap;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DocumentTemplate {
     /// Unique key within Clicksign
-    pub key: String,
+    pub key: TemplateKey,
     /// Data to fill in the template placeholders
     pub data: HashMap<String, String>,
 }
 
-/// Defines the field "data" of a document event.
+/// Defines the URLs for downloading the document files
 #[derive(Debug, Serialize, Deserialize)]
-pub struct EventData {
-    /// Information of the user who created the document
-    pub user: HashMap<String, String>,
-    /// Information about the Clicksign account in whinch the document was created
-    pub account: HashMap<String, String>,
-    // TODO: I'm not sure about the difference between this deadline_at and the Document.deadline_at
-    deadline_at: String,
-    // TODO: I'm not sure about the difference between this auto_close and the Document.auto_close
-    auto_close: bool,
-    // TODO: I'm not sure about the difference between this locale and the Document.locale
-    locale: String,
+pub struct DocumentDownloads {
+    /// URL of the uploaded (or generated) file
+    pub original_file_url: Option<String>,
+    /// URL of the signed file, available after the first signature
+    pub signed_file_url: Option<String>,
+    /// URL of a zip file with the original and the signed files
+    pub ziped_file_url: Option<String>,
 }
 
-/// This struct models a document event
+/// Defines the validation of a signature
 #[derive(Debug, Serialize, Deserialize)]
-pub struct DocumentEvent {
-    /// Event name
-    pub name: String,
-    /// Event data
-    pub data: EventData,
-    /// Datetime the event occurred
-    pub occurred_at: String
+pub struct SignatureValidation {
+    /// Validation status
+    pub status: Option<String>,
+    /// Name found in the validation
+    pub name: Option<String>,
+}
+
+/// Defines the signature of a signer in a document
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Signature {
+    /// Name informed by the signer when signing
+    pub name: Option<String>,
+    /// Email informed by the signer when signing
+    pub email: Option<String>,
+    /// Birthday informed by the signer when signing
+    pub birthday: Option<Date>,
+    /// CPF informed by the signer when signing
+    pub documentation: Option<String>,
+    /// Validation of the signature data
+    pub validation: Option<SignatureValidation>,
+    /// Signature datetime
+    pub signed_at: Option<DateTime>,
 }
 
-/// This struct defines a metainformation about the document
+/// Defines a signer as returned inside a document
 #[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentSigner {
+    /// Signer's unique key within Clicksign
+    pub key: SignerKey,
+    /// Key used to request the signature of this signer in the document
+    pub request_signature_key: Option<RequestSignatureKey>,
+    /// Key of the list relating the signer and the document
+    pub list_key: Option<ListKey>,
+    /// Email of the signer
+    pub email: Option<String>,
+    /// Phone number of the signer
+    pub phone_number: Option<String>,
+    /// Under what title the signature will be carried out
+    pub sign_as: Option<SignAs>,
+    /// Authentication types for signing
+    pub auths: Option<Vec<AuthMethod>>,
+    /// Signer's full name
+    pub name: Option<String>,
+    /// Signer's CPF
+    pub documentation: Option<String>,
+    /// Signer's date of birth
+    pub birthday: Option<Date>,
+    /// Whether the signer has a CPF
+    pub has_documentation: Option<bool>,
+    /// Datetime the signer was added to the document
+    pub created_at: Option<DateTime>,
+    /// Datetime of the last update of the signer in the document
+    pub updated_at: Option<DateTime>,
+    /// Signature data, present after the signer signs the document
+    pub signature: Option<Signature>,
+}
+
+/// This struct defines a metainformation about the document, as returned by Clicksign.
+/// It's also the request body for creating documents from a template; only the fields
+/// set to `Some` are sent.
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Document {
     /// Unique key within Clicksign
-    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<DocumentKey>,
     /// Full path for the document within Clicksign
     pub path: String,
     /// Name of generated file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filename: Option<String>,
+    /// Datetime the document was uploaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploaded_at: Option<DateTime>,
     /// Datetime for the last update in the document
-    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
     /// Document finalization datetime
-    pub finished_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime>,
     /// Document deadline
-    pub deadline_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline_at: Option<DateTime>,
     /// Status of the document
-    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DocumentStatus>,
     /// Indicates whether the document will be automatically finalized when all the signers sign.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_close: Option<bool>,
     /// Indicates the document's locale
-    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
     /// Metinformation about the document
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
     /// Missing information in the clicksign documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sequence_enabled: Option<bool>,
     /// Missing information in the clicksign documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signable_group: Option<String>,
     /// Missing information in the clicksign documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remind_interval: Option<String>,
     /// Document download information
-    pub downloads: Option<HashMap<String, String>>,
-    /// Document template data
-    pub template: DocumentTemplate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<DocumentDownloads>,
+    /// Document template data (only present in documents created by template)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<DocumentTemplate>,
     /// List of signers in the document
-    pub signers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<DocumentSigner>>,
     /// Lists of events that occurred in the document
-    pub events: Option<Vec<DocumentEvent>>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<DocumentEvent>>,
+    /// Lists relating the signers to the document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lists: Option<Vec<SignerToDocument>>,
+}
+
+/// This struct defines the request body for creating a document via upload.
+/// Only the fields that were set are sent to Clicksign.
+/// Check [clicksign docs](https://developers.clicksign.com/docs/criar-documento-via-upload) for detailed info.
+///
+/// # Example
+/// ```
+/// use clicksign::models::documents::NewDocument;
+/// use clicksign::models::enums::Locale;
+///
+/// let document = NewDocument::new("/Contratos/Contrato-123.pdf")
+///     .auto_close(true)
+///     .locale(Locale::PtBr);
+/// assert_eq!(
+///     r#"{"path":"/Contratos/Contrato-123.pdf","auto_close":true,"locale":"pt-BR"}"#,
+///     serde_json::to_string(&document).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewDocument {
+    /// Full path for the document within Clicksign, including the file extension
+    pub path: String,
+    /// File content encoded as a data URI, e.g. `data:application/pdf;base64,...`.
+    /// Set by the client from the uploaded content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_base64: Option<String>,
+    /// Document deadline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline_at: Option<DateTime>,
+    /// Indicates whether the document will be automatically finalized when all the signers sign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_close: Option<bool>,
+    /// Indicates the document's locale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Indicates whether the signers must sign in sequence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_enabled: Option<bool>,
+    /// Interval, in days, for reminding the signers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remind_interval: Option<String>,
+}
+
+impl NewDocument {
+    /// Given the full path of the document within Clicksign, creates a document with
+    /// the default settings of the account
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Given a deadline, returns the document with it
+    pub fn deadline_at(mut self, deadline_at: DateTime) -> Self {
+        self.deadline_at = Some(deadline_at);
+        self
+    }
+
+    /// Returns the document finalized automatically, or not, after the last signature
+    pub fn auto_close(mut self, auto_close: bool) -> Self {
+        self.auto_close = Some(auto_close);
+        self
+    }
+
+    /// Given a locale, returns the document with it
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Returns the document requiring, or not, the signers to sign in sequence
+    pub fn sequence_enabled(mut self, sequence_enabled: bool) -> Self {
+        self.sequence_enabled = Some(sequence_enabled);
+        self
+    }
+
+    /// Given an interval in days, returns the document reminding the signers with it
+    pub fn remind_interval(mut self, remind_interval: &str) -> Self {
+        self.remind_interval = Some(remind_interval.to_string());
+        self
+    }
+}
+
+/// Settings that can be changed in an existing document.
+/// Only the fields set to `Some` are sent to Clicksign.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DocumentConfiguration {
+    /// Document deadline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline_at: Option<DateTime>,
+    /// Indicates whether the document will be automatically finalized when all the signers sign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_close: Option<bool>,
+    /// Indicates the document's locale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Interval, in days, for reminding the signers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remind_interval: Option<String>,
+    /// Indicates whether the signers must sign in sequence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_enabled: Option<bool>,
+    /// Metinformation about the document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Pagination information returned by listing endpoints
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PageInfos {
+    /// Number of the current page
+    pub current_page: Option<u32>,
+    /// Number of the first page
+    pub first_page: Option<u32>,
+    /// Number of the previous page, if any
+    pub prev_page: Option<u32>,
+    /// Number of the next page, if any
+    pub next_page: Option<u32>,
+    /// Number of the last page
+    pub last_page: Option<u32>,
+}
+
+/// This struct models a page of the documents listing
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentsPage {
+    /// Documents in the page
+    pub documents: Vec<Document>,
+    /// Pagination information
+    pub page_infos: PageInfos,
+}
+
+/// Filters applied to the documents yielded by `Client::documents_stream`.
+/// Datetimes are compared by instant, whatever their offsets.
+#[derive(Debug, Default, Clone)]
+pub struct DocumentFilter {
+    /// Only documents with this status
+    pub status: Option<DocumentStatus>,
+    /// Only documents whose path is inside this folder (e.g. `/Contratos`)
+    pub folder: Option<String>,
+    /// Only documents updated at or after this datetime
+    pub updated_after: Option<DateTime>,
+    /// Only documents updated at or before this datetime
+    pub updated_before: Option<DateTime>,
+}
+
+impl DocumentFilter {
+    /// Returns whether the given document satisfies all the filters
+    pub fn matches(&self, document: &Document) -> bool {
+        if let Some(status) = &self.status {
+            if document.status.as_ref() != Some(status) {
+                return false;
+            }
+        }
+        if let Some(folder) = &self.folder {
+            let folder = format!("{}/", folder.trim_end_matches('/'));
+            if !document.path.starts_with(&folder) {
+                return false;
+            }
+        }
+        if self.updated_after.is_some() || self.updated_before.is_some() {
+            let updated_at = match document.updated_at.as_ref().and_then(DateTime::instant) {
+                Some(updated_at) => updated_at,
+                None => return false,
+            };
+            // A filter with an invalid datetime matches no document
+            if let Some(after) = &self.updated_after {
+                match after.instant() {
+                    Some(after) if updated_at >= after => {}
+                    _ => return false,
+                }
+            }
+            if let Some(before) = &self.updated_before {
+                match before.instant() {
+                    Some(before) if updated_at <= before => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
 }
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Generates an enum of the string values accepted by the Clicksign API, with an
+/// `Other` variant keeping any value not known by this crate, so new values sent
+/// by the API don't break deserialization.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:expr,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// Any other value, not known by this crate
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns the value used by the Clicksign API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)+
+                    other => $name::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok($name::from(String::deserialize(deserializer)?))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Authentication types for signing
+    AuthMethod {
+        /// Token sent by email
+        Email => "email",
+        /// Token sent by SMS
+        Sms => "sms",
+        /// Token sent by Whatsapp
+        Whatsapp => "whatsapp",
+        /// Signature via API, with the signer's secret
+        Api => "api",
+        /// Pix transfer from the signer's account
+        Pix => "pix",
+        /// ICP-Brasil digital certificate
+        IcpBrasil => "icp_brasil",
+        /// Facial biometrics
+        FacialBiometrics => "facial_biometrics",
+    }
+}
+
+string_enum! {
+    /// Under what title the signature is carried out
+    SignAs {
+        /// Signer
+        Sign => "sign",
+        /// Approver
+        Approve => "approve",
+        /// Party
+        Party => "party",
+        /// Witness
+        Witness => "witness",
+        /// Intervening party
+        Intervening => "intervening",
+        /// Acknowledging receipt
+        Receipt => "receipt",
+        /// Endorser
+        Endorser => "endorser",
+        /// Endorsee
+        Endorsee => "endorsee",
+        /// Administrator
+        Administrator => "administrator",
+        /// Guarantor
+        Guarantor => "guarantor",
+        /// Transferor
+        Transferor => "transferor",
+        /// Transferee
+        Transferee => "transferee",
+        /// Contractee
+        Contractee => "contractee",
+        /// Contractor
+        Contractor => "contractor",
+        /// Joint debtor
+        JointDebtor => "joint_debtor",
+        /// Issuer
+        Issuer => "issuer",
+        /// Manager
+        Manager => "manager",
+        /// Buyer
+        Buyer => "buyer",
+        /// Seller
+        Seller => "seller",
+        /// Attorney
+        Attorney => "attorney",
+        /// Legal representative
+        LegalRepresentative => "legal_representative",
+        /// Co-responsible
+        CoResponsible => "co_responsible",
+        /// Validator
+        Validator => "validator",
+        /// Ratifying
+        Ratify => "ratify",
+        /// Lessor
+        Lessor => "lessor",
+        /// Lessee
+        Lessee => "lessee",
+        /// Surety
+        Surety => "surety",
+        /// Assignor
+        Assignor => "assignor",
+        /// Assignee
+        Assignee => "assignee",
+        /// Accountant
+        Accountant => "accountant",
+        /// Beneficiary
+        Beneficiary => "beneficiary",
+    }
+}
+
+string_enum! {
+    /// How the signer receives the signature confirmation and the finalized document
+    Delivery {
+        /// By email
+        Email => "email",
+        /// By Whatsapp
+        Whatsapp => "whatsapp",
+    }
+}
+
+string_enum! {
+    /// Status of a document
+    DocumentStatus {
+        /// Waiting for signatures
+        Running => "running",
+        /// Finalized
+        Closed => "closed",
+        /// Canceled
+        Canceled => "canceled",
+    }
+}
+
+string_enum! {
+    /// Language of the document pages and notifications
+    Locale {
+        /// Brazilian Portuguese
+        PtBr => "pt-BR",
+        /// American English
+        EnUs => "en-US",
+    }
+}
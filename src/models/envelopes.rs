@@ -0,0 +1,34 @@
+use crate::models::batches::Batch;
+use crate::models::documents::Document;
+use crate::models::signers::{SignerResponse, SignerToDocument};
+use serde::{Deserialize, Serialize};
+
+/// Wraps a document in the `{"document": {...}}` body used by the documents endpoints.
+/// The type parameter allows partial documents, such as `DocumentConfiguration`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentEnvelope<T = Document> {
+    /// The wrapped document
+    pub document: T,
+}
+
+/// Wraps a signer in the `{"signer": {...}}` body used by the signers endpoints.
+/// Requests wrap a `NewSigner`, responses a `SignerResponse`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignerEnvelope<T = SignerResponse> {
+    /// The wrapped signer
+    pub signer: T,
+}
+
+/// Wraps a list in the `{"list": {...}}` body used by the lists endpoints
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListEnvelope<T = SignerToDocument> {
+    /// The wrapped list, relating a signer to a document
+    pub list: T,
+}
+
+/// Wraps a batch in the `{"batch": {...}}` body used by the batches endpoints
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchEnvelope<T = Batch> {
+    /// The wrapped batch
+    pub batch: T,
+}
@@ -0,0 +1,309 @@
+use crate::datetime::{Date, DateTime};
+use crate::models::enums::{AuthMethod, Locale, SignAs};
+use crate::models::keys::SignerKey;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Defines the user who triggered a document event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventUser {
+    /// Email of the user
+    pub email: Option<String>,
+    /// Name of the user
+    pub name: Option<String>,
+}
+
+/// Defines the Clicksign account in which a document event occurred
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventAccount {
+    /// Unique key of the account within Clicksign
+    pub key: Option<String>,
+}
+
+/// Defines a signer as sent in the data of a document event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventSigner {
+    /// Signer's unique key within Clicksign
+    pub key: Option<SignerKey>,
+    /// Email of the signer
+    pub email: Option<String>,
+    /// Signer's full name
+    pub name: Option<String>,
+    /// Phone number of the signer
+    pub phone_number: Option<String>,
+    /// Signer's CPF
+    pub documentation: Option<String>,
+    /// Signer's date of birth
+    pub birthday: Option<Date>,
+    /// Whether the signer has a CPF
+    pub has_documentation: Option<bool>,
+    /// Under what title the signature is carried out
+    pub sign_as: Option<SignAs>,
+    /// Authentication types for signing
+    pub auths: Option<Vec<AuthMethod>>,
+    /// Any other field sent for the signer
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the `upload` event, sent when the document is created
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UploadData {
+    /// Information of the user who created the document
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account in whinch the document was created
+    pub account: Option<EventAccount>,
+    /// Document deadline
+    pub deadline_at: Option<DateTime>,
+    /// Indicates whether the document will be automatically finalized
+    pub auto_close: Option<bool>,
+    /// Document locale
+    pub locale: Option<Locale>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the events that add or remove signers from the document
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignersData {
+    /// Information of the user who changed the signers
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The added or removed signers
+    pub signers: Option<Vec<EventSigner>>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the events triggered by a signer (e.g. `sign` and `refusal`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignerActionData {
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The signer who triggered the event
+    pub signer: Option<EventSigner>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the events triggered by a user of the account (e.g. `close` and `cancel`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserActionData {
+    /// Information of the user who triggered the event
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the events triggered by Clicksign itself (e.g. `auto_close` and `deadline`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SystemData {
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the `update_deadline` event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateDeadlineData {
+    /// Information of the user who changed the deadline
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The new document deadline
+    pub deadline_at: Option<DateTime>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the `update_auto_close` event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateAutoCloseData {
+    /// Information of the user who changed the setting
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The new auto close setting
+    pub auto_close: Option<bool>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the `update_locale` event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateLocaleData {
+    /// Information of the user who changed the locale
+    pub user: Option<EventUser>,
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The new document locale
+    pub locale: Option<Locale>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Data of the `acceptance_term_*` events, sent along the lifecycle of an acceptance term
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AcceptanceTermData {
+    /// Information about the Clicksign account
+    pub account: Option<EventAccount>,
+    /// The acceptance term, as sent by Clicksign
+    pub acceptance_term: Option<Value>,
+    /// Any other field sent in the event data
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The payload shared by every document event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Event<T> {
+    /// Event data
+    pub data: T,
+    /// Datetime the event occurred
+    pub occurred_at: DateTime,
+}
+
+/// A modeled event whose data doesn't match the payload expected by this crate, kept
+/// as sent by Clicksign so it never breaks the deserialization of a document
+#[derive(Debug, Clone, PartialEq)]
+pub struct MalformedEvent {
+    /// Event name, e.g. `close`
+    pub name: String,
+    /// Event as sent by Clicksign
+    pub raw: Value,
+    /// Why the event doesn't match the expected payload
+    pub error: String,
+}
+
+impl Serialize for MalformedEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
+
+macro_rules! document_events {
+    ($($(#[$doc:meta])* $variant:ident($data:ty) => $name:literal,)*) => {
+        /// This enum models a document event, tagged by the event name.
+        /// Events not modeled by this crate are kept as raw JSON in `Unknown`, and
+        /// modeled events whose data doesn't match the expected payload in `Malformed`.
+        #[derive(Debug, Serialize)]
+        #[serde(tag = "name")]
+        pub enum DocumentEvent {
+            $(
+                $(#[$doc])*
+                #[serde(rename = $name)]
+                $variant(Event<$data>),
+            )*
+            /// A modeled event whose data doesn't match the expected payload
+            #[serde(untagged)]
+            Malformed(MalformedEvent),
+            /// Any other event, as sent by Clicksign
+            #[serde(untagged)]
+            Unknown(Value),
+        }
+
+        impl<'de> Deserialize<'de> for DocumentEvent {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = Value::deserialize(deserializer)?;
+                let event = match value.get("name").and_then(Value::as_str) {
+                    $(Some($name) => match Event::deserialize(&value) {
+                        Ok(event) => DocumentEvent::$variant(event),
+                        Err(e) => DocumentEvent::Malformed(MalformedEvent {
+                            name: $name.to_string(),
+                            error: e.to_string(),
+                            raw: value,
+                        }),
+                    },)*
+                    _ => DocumentEvent::Unknown(value),
+                };
+                Ok(event)
+            }
+        }
+
+        impl DocumentEvent {
+            /// Returns the event name, as sent by Clicksign
+            pub fn name(&self) -> &str {
+                match self {
+                    $(DocumentEvent::$variant(_) => $name,)*
+                    DocumentEvent::Malformed(event) => &event.name,
+                    DocumentEvent::Unknown(value) => {
+                        value.get("name").and_then(Value::as_str).unwrap_or_default()
+                    }
+                }
+            }
+
+            /// Returns the datetime the event occurred, as sent by Clicksign
+            pub fn occurred_at(&self) -> Option<&str> {
+                match self {
+                    $(DocumentEvent::$variant(event) => Some(event.occurred_at.as_str()),)*
+                    DocumentEvent::Malformed(MalformedEvent { raw: value, .. })
+                    | DocumentEvent::Unknown(value) => {
+                        value.get("occurred_at").and_then(Value::as_str)
+                    }
+                }
+            }
+        }
+    };
+}
+
+document_events! {
+    /// The document was created
+    Upload(UploadData) => "upload",
+    /// Signers were added to the document
+    AddSigner(SignersData) => "add_signer",
+    /// Signers were removed from the document
+    RemoveSigner(SignersData) => "remove_signer",
+    /// A signer signed the document
+    Sign(SignerActionData) => "sign",
+    /// A signer refused to sign the document
+    Refusal(SignerActionData) => "refusal",
+    /// A signer's biometric validation was refused
+    BiometricRefused(SignerActionData) => "biometric_refused",
+    /// A signer exceeded the attempts of authentication by Whatsapp
+    AttemptsByWhatsappExceeded(SignerActionData) => "attempts_by_whatsapp_exceeded",
+    /// The document was finished by a user
+    Close(UserActionData) => "close",
+    /// The document was automatically finished after the last signature
+    AutoClose(SystemData) => "auto_close",
+    /// The signed file of the finished document is available
+    DocumentClosed(SystemData) => "document_closed",
+    /// The document reached its deadline
+    Deadline(SystemData) => "deadline",
+    /// The document was canceled
+    Cancel(UserActionData) => "cancel",
+    /// The document deadline was changed
+    UpdateDeadline(UpdateDeadlineData) => "update_deadline",
+    /// The document auto close setting was changed
+    UpdateAutoClose(UpdateAutoCloseData) => "update_auto_close",
+    /// The document locale was changed
+    UpdateLocale(UpdateLocaleData) => "update_locale",
+    /// An image was added to the document
+    AddImage(UserActionData) => "add_image",
+    /// An acceptance term was enqueued for sending
+    AcceptanceTermEnqueued(AcceptanceTermData) => "acceptance_term_enqueued",
+    /// An acceptance term was sent
+    AcceptanceTermSent(AcceptanceTermData) => "acceptance_term_sent",
+    /// An acceptance term was accepted
+    AcceptanceTermCompleted(AcceptanceTermData) => "acceptance_term_completed",
+    /// An acceptance term was refused
+    AcceptanceTermRefused(AcceptanceTermData) => "acceptance_term_refused",
+    /// An acceptance term was canceled
+    AcceptanceTermCanceled(AcceptanceTermData) => "acceptance_term_canceled",
+    /// An acceptance term expired
+    AcceptanceTermExpired(AcceptanceTermData) => "acceptance_term_expired",
+    /// An acceptance term could not be sent
+    AcceptanceTermError(AcceptanceTermData) => "acceptance_term_error",
+}
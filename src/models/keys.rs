@@ -0,0 +1,147 @@
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Given a text, returns whether it is a UUID in the hyphenated form Clicksign uses
+/// for its keys, e.g. `27b02527-a576-46ee-b01c-bb4e694036c4`
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12].iter())
+            .all(|(group, len)| group.len() == *len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Generates a newtype for a kind of Clicksign key, so keys of different resources
+/// can't be swapped. Keys are validated as UUIDs when parsed or deserialized and
+/// serialized as plain strings.
+macro_rules! key_type {
+    ($(#[$meta:meta])* $name:ident => $description:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Given a text, returns the key, failing when it is not a UUID
+            pub fn parse(value: &str) -> Result<Self, Error> {
+                if is_uuid(value) {
+                    Ok($name(value.to_string()))
+                } else {
+                    Err(Error::InvalidInput(format!(
+                        "Invalid {}, expected a UUID: {}",
+                        $description, value
+                    )))
+                }
+            }
+
+            /// Returns the key as text
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                $name::parse(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = Error;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $name::parse(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::parse(&value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for &str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                $name::parse(&value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+key_type! {
+    /// Unique key of a document within Clicksign
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::models::keys::DocumentKey;
+    ///
+    /// let key: DocumentKey = "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap();
+    /// assert_eq!("27b02527-a576-46ee-b01c-bb4e694036c4", key.as_str());
+    /// assert!(DocumentKey::parse("Contrato-123").is_err());
+    /// ```
+    DocumentKey => "document key"
+}
+
+key_type! {
+    /// Unique key of a signer within Clicksign
+    SignerKey => "signer key"
+}
+
+key_type! {
+    /// Unique key of a list, relating a signer to a document
+    ListKey => "list key"
+}
+
+key_type! {
+    /// Key used to request the signature of a signer in a document
+    RequestSignatureKey => "request signature key"
+}
+
+key_type! {
+    /// Unique key of a document template within Clicksign
+    TemplateKey => "template key"
+}
+
+key_type! {
+    /// Unique key of a batch within Clicksign
+    BatchKey => "batch key"
+}
@@ -2,3 +2,15 @@
 pub mod signers;
 /// Request/Response models for documents
 pub mod documents;
+/// Request/Response models for batches
+pub mod batches;
+/// Request/Response models for notifications
+pub mod notifications;
+/// Models for document events, used in documents and webhooks
+pub mod events;
+/// Envelopes wrapping the models in request and response bodies
+pub mod envelopes;
+/// Enums of the values accepted by the Clicksign API
+pub mod enums;
+/// Typed keys of the Clicksign resources
+pub mod keys;
@@ -0,0 +1,37 @@
+use crate::models::keys::RequestSignatureKey;
+use serde::{Deserialize, Serialize};
+
+/// This struct defines a request body for POST /api/v1/notifications endpoint
+/// Check [clicksign docs](https://developers.clicksign.com/docs/solicitar-assinatura-por-email) for detailed info.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Notification {
+    /// The `request_signature_key` of the signer in the document
+    pub request_signature_key: RequestSignatureKey,
+    /// The message sent in the body of the notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// URL the signer is redirected to after signing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Channels through which a signer can be notified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationChannel {
+    /// Notification by email
+    Email,
+    /// Notification by Whatsapp
+    Whatsapp,
+    /// Notification by SMS
+    Sms,
+}
+
+/// This struct defines the result of a notification accepted by Clicksign
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationResult {
+    /// The channel through which the signer was notified
+    pub channel: NotificationChannel,
+    /// The `request_signature_key` of the notified signer
+    pub request_signature_key: RequestSignatureKey,
+}
@@ -1,63 +1,345 @@
+use crate::datetime::{Date, DateTime};
+use crate::error::Error;
+use crate::models::enums::{AuthMethod, Delivery, SignAs};
+use crate::models::keys::{DocumentKey, ListKey, RequestSignatureKey, SignerKey};
+use crate::validation::{
+    format_cpf, is_valid_birthday, is_valid_cpf, is_valid_email, normalize_phone_number,
+    FieldError, INVALID_PHONE_NUMBER,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
-/// This struct defines a signer with the attributes described in the API documentation
-/// The optional fields only make sense in the Responses body.
+/// This struct defines the request body for creating a signer.
+/// Only the fields that were set are sent to Clicksign.
 /// Check [clicksign docs](https://developers.clicksign.com/docs/criar-signatario) for detailed info.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Signer {
-    /// Unique identifier for the signer into clicksign environment
-    pub key: Option<String>,
+///
+/// # Example
+/// ```
+/// use clicksign::models::enums::AuthMethod;
+/// use clicksign::models::signers::NewSigner;
+///
+/// let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+///     .phone_number("11999999999")
+///     .auths(&[AuthMethod::Email, AuthMethod::Sms])
+///     .documentation("123.321.123-40")
+///     .birthday("1983-03-31".parse().unwrap());
+///
+/// let foreigner = NewSigner::new("John Doe", "john@example.com").without_documentation();
+/// assert_eq!(
+///     r#"{"email":"john@example.com","auths":["email"],"name":"John Doe","has_documentation":false}"#,
+///     serde_json::to_string(&foreigner).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewSigner {
     /// Email of the signer who must sign the document
     pub email: String,
     /// Phone number for sending Whatsapp or SMS. (Must be sent 11 numbers)
-    pub phone_number: String,
-    /// Authentication type for signing (email, sms, whatsapp, API and/or pix).
-    pub auths: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Authentication types for signing (email, sms, whatsapp, API and/or pix).
+    pub auths: Vec<AuthMethod>,
     /// Signer's full name.
     pub name: String,
     /// Signer's CPF
-    pub documentation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
     /// Signer's date of birth
-    pub birthday: String,
-    /// The default value is true. If false, isn't possible to send the fields "documentation" and "birthday".
-    pub has_documentation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<Date>,
+    /// Clicksign assumes true. If false, isn't possible to send the fields "documentation" and "birthday".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_documentation: Option<bool>,
     /// Informs how the signer receives the signature confirmation and finalized document notifications.
-    pub delivery: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<Delivery>,
     /// Check [clicksign documentation](https://developers.clicksign.com/docs/criar-signatario#atributos-para-a-cria%C3%A7%C3%A3o-de-signat%C3%A1rios) for more info about this field.
-    pub selfie_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie_enabled: Option<bool>,
     /// Check [clicksign documentation](https://developers.clicksign.com/docs/criar-signatario#atributos-para-a-cria%C3%A7%C3%A3o-de-signat%C3%A1rios) for more info about this field.
-    pub handwritten_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handwritten_enabled: Option<bool>,
     /// Check [clicksign documentation](https://developers.clicksign.com/docs/criar-signatario#atributos-para-a-cria%C3%A7%C3%A3o-de-signat%C3%A1rios) for more info about this field.
-    pub official_document_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub official_document_enabled: Option<bool>,
     /// Check [clicksign documentation](https://developers.clicksign.com/docs/criar-signatario#atributos-para-a-cria%C3%A7%C3%A3o-de-signat%C3%A1rios) for more info about this field.
-    pub liveness_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liveness_enabled: Option<bool>,
+}
+
+impl NewSigner {
+    /// Given the signer's name and email, creates a signer authenticated by email
+    pub fn new(name: &str, email: &str) -> Self {
+        Self {
+            email: email.to_string(),
+            phone_number: None,
+            auths: vec![AuthMethod::Email],
+            name: name.to_string(),
+            documentation: None,
+            birthday: None,
+            has_documentation: None,
+            delivery: None,
+            selfie_enabled: None,
+            handwritten_enabled: None,
+            official_document_enabled: None,
+            liveness_enabled: None,
+        }
+    }
+
+    /// Given a phone number, returns the signer with it.
+    /// Valid mobile numbers are normalized to 11 digits, e.g. `+55 (11) 99999-9999` to `11999999999`.
+    pub fn phone_number(mut self, phone_number: &str) -> Self {
+        self.phone_number =
+            Some(normalize_phone_number(phone_number).unwrap_or_else(|| phone_number.to_string()));
+        self
+    }
+
+    /// Given the authentication types, returns the signer using them instead of email
+    pub fn auths(mut self, auths: &[AuthMethod]) -> Self {
+        self.auths = auths.to_vec();
+        self
+    }
+
+    /// Given a CPF, returns the signer with it.
+    /// Valid CPFs are formatted as expected by Clicksign, e.g. `12332112340` to `123.321.123-40`.
+    pub fn documentation(mut self, documentation: &str) -> Self {
+        self.documentation =
+            Some(format_cpf(documentation).unwrap_or_else(|| documentation.to_string()));
+        self.has_documentation = Some(true);
+        self
+    }
+
+    /// Given a date of birth, returns the signer with it
+    pub fn birthday(mut self, birthday: Date) -> Self {
+        self.birthday = Some(birthday);
+        self.has_documentation = Some(true);
+        self
+    }
+
+    /// Returns the signer without CPF nor date of birth, e.g. for foreigners
+    pub fn without_documentation(mut self) -> Self {
+        self.documentation = None;
+        self.birthday = None;
+        self.has_documentation = Some(false);
+        self
+    }
+
+    /// Given how the signer receives the notifications, returns the signer with it
+    pub fn delivery(mut self, delivery: Delivery) -> Self {
+        self.delivery = Some(delivery);
+        self
+    }
+
+    /// Returns the signer requiring a selfie to sign
+    pub fn selfie_enabled(mut self, enabled: bool) -> Self {
+        self.selfie_enabled = Some(enabled);
+        self
+    }
+
+    /// Returns the signer requiring a handwritten signature to sign
+    pub fn handwritten_enabled(mut self, enabled: bool) -> Self {
+        self.handwritten_enabled = Some(enabled);
+        self
+    }
+
+    /// Returns the signer requiring an official document photo to sign
+    pub fn official_document_enabled(mut self, enabled: bool) -> Self {
+        self.official_document_enabled = Some(enabled);
+        self
+    }
+
+    /// Returns the signer requiring a liveness check to sign
+    pub fn liveness_enabled(mut self, enabled: bool) -> Self {
+        self.liveness_enabled = Some(enabled);
+        self
+    }
+
+    /// Returns the signer with its valid phone number and CPF in the format expected by
+    /// Clicksign, e.g. `+55 (11) 99999-9999` to `11999999999` and `12332112340` to
+    /// `123.321.123-40`. Invalid values are kept as they are.
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::models::signers::NewSigner;
+    ///
+    /// let signer = NewSigner {
+    ///     phone_number: Some("+55 (11) 99999-9999".to_string()),
+    ///     documentation: Some("12332112340".to_string()),
+    ///     ..NewSigner::new("Marcos Zumba", "fulano@example.com")
+    /// }
+    /// .normalized();
+    /// assert_eq!(Some("11999999999".to_string()), signer.phone_number);
+    /// assert_eq!(Some("123.321.123-40".to_string()), signer.documentation);
+    /// ```
+    pub fn normalized(mut self) -> Self {
+        if let Some(phone_number) = self.phone_number.as_deref().and_then(normalize_phone_number) {
+            self.phone_number = Some(phone_number);
+        }
+        if let Some(documentation) = self.documentation.as_deref().and_then(format_cpf) {
+            self.documentation = Some(documentation);
+        }
+        self
+    }
+
+    /// Checks the fields Clicksign would refuse: the email syntax, the phone number,
+    /// required by SMS and Whatsapp authentication, the CPF check digits and the date of birth.
+    /// Phone numbers and CPFs are accepted in any format `normalized` handles.
+    /// Returns `Error::InvalidFields` with every refused field.
+    ///
+    /// # Example
+    /// ```
+    /// use clicksign::models::signers::NewSigner;
+    /// use clicksign::Error;
+    ///
+    /// let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+    ///     .phone_number("+55 (11) 99999-9999")
+    ///     .documentation("12332112340");
+    /// assert!(signer.validate().is_ok());
+    ///
+    /// let signer = NewSigner::new("Marcos Zumba", "fulano").documentation("123.321.123-41");
+    /// match signer.validate() {
+    ///     Err(Error::InvalidFields(errors)) => assert_eq!(2, errors.len()),
+    ///     result => panic!("Unexpected result: {:?}", result),
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+        if self.name.trim().is_empty() {
+            errors.push(FieldError::new("name", "must not be empty"));
+        }
+        if !is_valid_email(&self.email) {
+            errors.push(FieldError::new("email", "must be a valid email"));
+        }
+        match &self.phone_number {
+            Some(phone_number) => {
+                if normalize_phone_number(phone_number).is_none() {
+                    errors.push(FieldError::new("phone_number", INVALID_PHONE_NUMBER));
+                }
+            }
+            None => {
+                if self
+                    .auths
+                    .iter()
+                    .any(|auth| matches!(auth, AuthMethod::Sms | AuthMethod::Whatsapp))
+                {
+                    errors.push(FieldError::new(
+                        "phone_number",
+                        "is required for SMS and Whatsapp authentication",
+                    ));
+                }
+            }
+        }
+        if let Some(documentation) = &self.documentation {
+            if !is_valid_cpf(documentation) {
+                errors.push(FieldError::new(
+                    "documentation",
+                    "must be a valid CPF, e.g. 123.321.123-40",
+                ));
+            }
+        }
+        if let Some(birthday) = &self.birthday {
+            if !is_valid_birthday(birthday) {
+                errors.push(FieldError::new(
+                    "birthday",
+                    "must be a past date, at most 130 years ago",
+                ));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidFields(errors))
+        }
+    }
+}
+
+/// This struct defines a signer as returned by Clicksign.
+/// Every field is optional and unknown fields are kept in `extra`, so responses
+/// round-trip even when the API adds or omits fields.
+/// Check [clicksign docs](https://developers.clicksign.com/docs/visualizar-signatario) for detailed info.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignerResponse {
+    /// Unique identifier for the signer into clicksign environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<SignerKey>,
+    /// Email of the signer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Phone number for sending Whatsapp or SMS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Authentication types for signing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auths: Option<Vec<AuthMethod>>,
+    /// Signer's full name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Signer's CPF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+    /// Signer's date of birth
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<Date>,
+    /// Whether the signer has a CPF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_documentation: Option<bool>,
+    /// How the signer receives the notifications
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<Delivery>,
+    /// Whether a selfie is required to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie_enabled: Option<bool>,
+    /// Whether a handwritten signature is required to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handwritten_enabled: Option<bool>,
+    /// Whether an official document photo is required to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub official_document_enabled: Option<bool>,
+    /// Whether a liveness check is required to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liveness_enabled: Option<bool>,
     /// Signer creation datetime
-    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
     /// Signer update datetime
-    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Any other field returned for the signer
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// This struct defines a request and response body for POST /api/v1/lists endpoint
-/// The optional fields only make sense in the Responses body.
+/// Apart from `message`, the optional fields only make sense in the Responses body. Unset fields are not sent.
 /// Check [clicksign docs](https://developers.clicksign.com/docs/adicionar-signatario-a-documento) for detailed info.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SignerToDocument {
     /// A key for clicksign internal stuffs (Response-only field)
-    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<ListKey>,
     /// A key for clicksign internal stuffs (Response-only field)
-    pub request_signature_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_signature_key: Option<RequestSignatureKey>,
     /// Unique key of the document within Clicksign
-    pub document_key: String,
+    pub document_key: DocumentKey,
     /// Signer's unique key within Clicksign
-    pub signer_key: String,
+    pub signer_key: SignerKey,
     /// Under what title the signature will be carried out
-    pub sign_as: String,
+    pub sign_as: SignAs,
     /// Event creation datetime (Response-only field)
-    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
     /// Event update datetime (Response-only field)
-    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
     /// URL to signing document (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Signing group of the signer, when the document has sequential signing (Response-only field)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<u32>,
     /// The message will be sent in the body of the signature request email to the signers.
-    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
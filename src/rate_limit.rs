@@ -0,0 +1,110 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+/// Headers in which the time for the rate limit reset may be sent
+const RATE_LIMIT_RESET_HEADERS: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+
+/// Given response headers, returns how long to wait before sending new requests.
+/// `Retry-After` is read in seconds. The rate limit reset headers are read as a unix
+/// timestamp when large enough to be one, or in seconds otherwise.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+    if let Some(retry_after) = seconds(RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(retry_after));
+    }
+    let reset = RATE_LIMIT_RESET_HEADERS.iter().find_map(|name| seconds(name))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    if reset > 1_000_000_000 {
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    } else {
+        Some(Duration::from_secs(reset))
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+/// Token-bucket rate limiter for the requests sent by a client.
+/// The bucket starts full and each request takes one token from it; tokens are
+/// put back continuously at the configured rate.
+///
+/// # Example
+/// ```
+/// use clicksign::client::Client;
+/// use clicksign::rate_limit::RateLimiter;
+/// use std::time::Duration;
+///
+/// // Bursts of up to 10 requests, then 5 requests per second
+/// let limiter = RateLimiter::new(10, 5, Duration::from_secs(1));
+/// let client = Client::new("some_access_token", None).with_rate_limiter(limiter);
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    tokens_per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// Given the bucket capacity and how many requests are allowed per period, creates a rate limiter
+    ///
+    /// # Arguments
+    /// * capacity (u32): Maximum number of requests sent in a burst
+    /// * requests (u32): Number of requests allowed in each period
+    /// * period (Duration): The period in which `requests` are allowed
+    pub fn new(capacity: u32, requests: u32, period: Duration) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        Self {
+            capacity,
+            tokens_per_second: f64::from(requests.max(1)) / period.as_secs_f64().max(f64::EPSILON),
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent, taking a token from the bucket
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = Instant::now();
+                match bucket.paused_until {
+                    Some(paused_until) if paused_until > now => paused_until - now,
+                    _ => {
+                        bucket.paused_until = None;
+                        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+                        bucket.tokens =
+                            (bucket.tokens + elapsed * self.tokens_per_second).min(self.capacity);
+                        bucket.refilled_at = now;
+                        if bucket.tokens >= 1.0 {
+                            bucket.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - bucket.tokens) / self.tokens_per_second)
+                    }
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Stops handing out tokens for the given duration, e.g. after clicksign answers with 429
+    pub async fn pause(&self, duration: Duration) {
+        let mut bucket = self.bucket.lock().await;
+        let paused_until = Instant::now() + duration;
+        bucket.tokens = 0.0;
+        bucket.refilled_at = paused_until;
+        bucket.paused_until = Some(match bucket.paused_until {
+            Some(current) if current > paused_until => current,
+            _ => paused_until,
+        });
+    }
+}
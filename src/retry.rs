@@ -0,0 +1,123 @@
+use crate::error::Error;
+use reqwest::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Defines how the client retries requests that failed with transient errors.
+///
+/// Retries apply automatically to idempotent requests (GET and DELETE). Requests with
+/// other methods (e.g. POST) are only retried when `retry_non_idempotent` is enabled,
+/// since Clicksign may have processed them before failing.
+///
+/// # Example
+/// ```
+/// use clicksign::client::Client;
+/// use clicksign::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 5,
+///     base_delay: Duration::from_secs(1),
+///     ..Default::default()
+/// };
+/// let client = Client::new("some_access_token", None).with_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry. It doubles on each following retry.
+    pub base_delay: Duration,
+    /// Maximum delay between two attempts, also capping the `Retry-After` header
+    pub max_delay: Duration,
+    /// Fraction of the delay (from `0.0` to `1.0`) randomly subtracted from it, so
+    /// many clients failing at the same time don't retry at the same time
+    pub jitter: f64,
+    /// Response status codes that are retried
+    pub retryable_statuses: Vec<StatusCode>,
+    /// Whether connection errors and timeouts are retried
+    pub retry_transport_errors: bool,
+    /// Whether the `Retry-After` header (in seconds) replaces the computed delay
+    pub respect_retry_after: bool,
+    /// Whether requests that are not idempotent (e.g. POST and PATCH) are retried
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+            retryable_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_transport_errors: true,
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Given a request method, returns how many attempts can be made for it
+    pub(crate) fn attempts_for(&self, method: &Method) -> u32 {
+        let idempotent = matches!(*method, Method::GET | Method::DELETE | Method::HEAD);
+        if idempotent || self.retry_non_idempotent {
+            self.max_attempts.max(1)
+        } else {
+            1
+        }
+    }
+
+    /// Given the error of an attempt, returns whether it can be retried
+    pub(crate) fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Transport(_) | Error::Timeout(_) => self.retry_transport_errors,
+            Error::RateLimited { .. } => self
+                .retryable_statuses
+                .contains(&StatusCode::TOO_MANY_REQUESTS),
+            Error::Server { status, .. } | Error::UnexpectedStatus { status, .. } => {
+                self.retryable_statuses.contains(status)
+            }
+            _ => false,
+        }
+    }
+
+    /// Given the number of the failed attempt (starting at 1) and the `Retry-After`
+    /// of its response, returns how long to wait before the next attempt, up to `max_delay`
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let (true, Some(retry_after)) = (self.respect_retry_after, retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0) * random_fraction();
+        delay.mul_f64(1.0 - jitter)
+    }
+}
+
+/// Returns a pseudo-random number between 0.0 and 1.0, good enough for jitter
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
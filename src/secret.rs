@@ -0,0 +1,74 @@
+use std::fmt;
+use url::Url;
+
+/// Text shown in place of secrets
+const REDACTED: &str = "[REDACTED]";
+
+/// Access token provided by clicksign.
+/// Its `Debug` and `Display` outputs are redacted, so it doesn't leak into logs.
+///
+/// # Example
+/// ```
+/// use clicksign::secret::AccessToken;
+///
+/// let token = AccessToken::new("c9d91ece-9b3b-4def-abac-25b645cb083c");
+/// assert_eq!("AccessToken([REDACTED])", format!("{:?}", token));
+/// assert_eq!("c9d91ece-9b3b-4def-abac-25b645cb083c", token.expose_secret());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct AccessToken(String);
+
+impl AccessToken {
+    /// Given the token provided by clicksign, wraps it
+    pub fn new(token: impl Into<String>) -> Self {
+        AccessToken(token.into())
+    }
+
+    /// Returns the token itself. Avoid logging it.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for AccessToken {
+    fn from(token: &str) -> Self {
+        AccessToken::new(token)
+    }
+}
+
+impl From<String> for AccessToken {
+    fn from(token: String) -> Self {
+        AccessToken::new(token)
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AccessToken({})", REDACTED)
+    }
+}
+
+impl fmt::Display for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Given a URL, replaces the value of its `access_token` query parameter with a redacted text
+pub(crate) fn mask_access_token(url: &mut Url) {
+    if !url.query_pairs().any(|(name, _)| name == "access_token") {
+        return;
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if name == "access_token" {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
@@ -0,0 +1,264 @@
+use crate::datetime::{civil_date, Date};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Oldest age accepted for a signer's date of birth
+const MAX_AGE_YEARS: i64 = 130;
+
+/// Why a phone number refused by `normalize_phone_number` is invalid
+pub(crate) const INVALID_PHONE_NUMBER: &str =
+    "must be a mobile number with 11 digits, e.g. 11999999999";
+
+/// Defines a field refused by the local validation, before calling Clicksign
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Name of the field, as sent to Clicksign (e.g. `documentation`)
+    pub field: &'static str,
+    /// Why the field was refused
+    pub message: String,
+}
+
+impl FieldError {
+    pub(crate) fn new(field: &'static str, message: &str) -> Self {
+        Self {
+            field,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Given a document number, formatted or not, returns its digits.
+/// Returns None if it has any character other than digits and `.`, `-`, `/` or spaces.
+fn digits(value: &str) -> Option<Vec<u32>> {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '.' | '-' | '/' | ' '))
+        .map(|c| c.to_digit(10))
+        .collect()
+}
+
+/// Given the digits before a check digit and their weights, returns the check digit
+fn check_digit(digits: &[u32], weights: &[u32]) -> u32 {
+    let sum: u32 = digits.iter().zip(weights).map(|(d, w)| d * w).sum();
+    match sum % 11 {
+        0 | 1 => 0,
+        rest => 11 - rest,
+    }
+}
+
+/// Given the digits of a document, returns whether the last two are valid check digits.
+/// Sequences of a single repeated digit pass the check but are never issued, so they are refused.
+fn has_valid_check_digits(digits: &[u32], weights: &[u32]) -> bool {
+    let len = digits.len();
+    if digits.iter().all(|d| *d == digits[0]) {
+        return false;
+    }
+    check_digit(&digits[..len - 2], &weights[1..]) == digits[len - 2]
+        && check_digit(&digits[..len - 1], weights) == digits[len - 1]
+}
+
+/// Given a CPF, formatted (`123.321.123-40`) or not (`12332112340`), returns whether
+/// its check digits are valid
+///
+/// # Example
+/// ```
+/// use clicksign::validation::is_valid_cpf;
+///
+/// assert!(is_valid_cpf("123.321.123-40"));
+/// assert!(!is_valid_cpf("123.321.123-41"));
+/// ```
+pub fn is_valid_cpf(cpf: &str) -> bool {
+    match digits(cpf) {
+        Some(digits) if digits.len() == 11 => {
+            has_valid_check_digits(&digits, &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2])
+        }
+        _ => false,
+    }
+}
+
+/// Given a valid CPF, returns it in the format expected by Clicksign, e.g. `123.321.123-40`.
+/// Returns None if the CPF is invalid.
+///
+/// # Example
+/// ```
+/// use clicksign::validation::format_cpf;
+///
+/// assert_eq!(Some("123.321.123-40".to_string()), format_cpf("12332112340"));
+/// assert_eq!(None, format_cpf("12332112341"));
+/// ```
+pub fn format_cpf(cpf: &str) -> Option<String> {
+    if !is_valid_cpf(cpf) {
+        return None;
+    }
+    let digits: String = cpf.chars().filter(char::is_ascii_digit).collect();
+    Some(format!(
+        "{}.{}.{}-{}",
+        &digits[..3],
+        &digits[3..6],
+        &digits[6..9],
+        &digits[9..]
+    ))
+}
+
+/// Given a CNPJ, formatted (`11.222.333/0001-81`) or not (`11222333000181`), returns
+/// whether its check digits are valid
+///
+/// # Example
+/// ```
+/// use clicksign::validation::is_valid_cnpj;
+///
+/// assert!(is_valid_cnpj("11.222.333/0001-81"));
+/// assert!(!is_valid_cnpj("11.222.333/0001-80"));
+/// ```
+pub fn is_valid_cnpj(cnpj: &str) -> bool {
+    match digits(cnpj) {
+        Some(digits) if digits.len() == 14 => {
+            has_valid_check_digits(&digits, &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2])
+        }
+        _ => false,
+    }
+}
+
+/// Given a valid CNPJ, returns it formatted, e.g. `11.222.333/0001-81`.
+/// Returns None if the CNPJ is invalid.
+///
+/// # Example
+/// ```
+/// use clicksign::validation::format_cnpj;
+///
+/// assert_eq!(Some("11.222.333/0001-81".to_string()), format_cnpj("11222333000181"));
+/// ```
+pub fn format_cnpj(cnpj: &str) -> Option<String> {
+    if !is_valid_cnpj(cnpj) {
+        return None;
+    }
+    let digits: String = cnpj.chars().filter(char::is_ascii_digit).collect();
+    Some(format!(
+        "{}.{}.{}/{}-{}",
+        &digits[..2],
+        &digits[2..5],
+        &digits[5..8],
+        &digits[8..12],
+        &digits[12..]
+    ))
+}
+
+/// Given a Brazilian mobile number, returns it with the 11 digits expected by Clicksign:
+/// the area code followed by the 9 digits of the number.
+/// The `+55` country code and punctuation are removed.
+/// Returns None if it isn't a valid mobile number.
+///
+/// # Example
+/// ```
+/// use clicksign::validation::normalize_phone_number;
+///
+/// assert_eq!(
+///     Some("11999999999".to_string()),
+///     normalize_phone_number("+55 (11) 99999-9999")
+/// );
+/// assert_eq!(None, normalize_phone_number("(11) 3333-3333"));
+/// ```
+pub fn normalize_phone_number(phone_number: &str) -> Option<String> {
+    let phone_number = phone_number.trim();
+    if !phone_number
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '+' | '(' | ')' | '-' | '.' | ' '))
+    {
+        return None;
+    }
+    let digits: String = phone_number.chars().filter(char::is_ascii_digit).collect();
+    let digits = match digits.len() {
+        13 if digits.starts_with("55") => &digits[2..],
+        11 if !phone_number.starts_with('+') => &digits[..],
+        _ => return None,
+    };
+    let bytes = digits.as_bytes();
+    // Area codes have no zeros and mobile numbers start with 9
+    if bytes[0] == b'0' || bytes[1] == b'0' || bytes[2] != b'9' {
+        return None;
+    }
+    Some(digits.to_string())
+}
+
+/// Given an email, returns whether its syntax is valid: a local part and a domain with
+/// at least two labels, separated by a single `@`
+///
+/// # Example
+/// ```
+/// use clicksign::validation::is_valid_email;
+///
+/// assert!(is_valid_email("fulano@example.com"));
+/// assert!(!is_valid_email("fulano@example"));
+/// ```
+pub fn is_valid_email(email: &str) -> bool {
+    let (local, domain) = match email.split_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let valid_local = !local.is_empty()
+        && local.len() <= 64
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_domain = labels.len() >= 2
+        && domain.len() <= 253
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    valid_local && valid_domain
+}
+
+/// Returns the current UTC date as year, month and day
+fn today() -> (i64, u32, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+    // Converts days since 1970-01-01 to a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Given a date of birth, returns whether it's plausible: a valid date, not in the
+/// future and at most 130 years ago
+///
+/// # Example
+/// ```
+/// use clicksign::datetime::Date;
+/// use clicksign::validation::is_valid_birthday;
+///
+/// assert!(is_valid_birthday(&"1983-03-31".parse::<Date>().unwrap()));
+/// assert!(!is_valid_birthday(&"1883-03-31".parse::<Date>().unwrap()));
+/// ```
+pub fn is_valid_birthday(birthday: &Date) -> bool {
+    match civil_date(birthday.as_str()) {
+        Some(birthday) => {
+            let today = today();
+            birthday <= today && birthday.0 >= today.0 - MAX_AGE_YEARS
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,349 @@
+use crate::dedupe::DedupeStore;
+use crate::error::Error;
+use crate::models::documents::Document;
+use crate::models::events::{
+    AcceptanceTermData, DocumentEvent, Event, MalformedEvent, SignerActionData, SignersData,
+    SystemData, UpdateAutoCloseData, UpdateDeadlineData, UpdateLocaleData, UploadData,
+    UserActionData,
+};
+use crate::webhooks::{parse_webhook, process_once, Processed, CONTENT_HMAC_HEADER};
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use std::sync::Arc;
+
+pub use async_trait::async_trait;
+
+/// Result returned by the methods of a `WebhookHandler`.
+/// An error answers the webhook with 500, so clicksign sends it again later.
+pub type HandlerResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+/// Handles the events received by the webhook router.
+/// Every method has a default implementation that ignores the event, so only the
+/// relevant ones need to be implemented.
+#[async_trait]
+pub trait WebhookHandler: Send + Sync + 'static {
+    /// Called on `upload` events
+    async fn on_upload(&self, _event: Event<UploadData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `add_signer` events
+    async fn on_add_signer(&self, _event: Event<SignersData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `remove_signer` events
+    async fn on_remove_signer(&self, _event: Event<SignersData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `sign` events
+    async fn on_sign(&self, _event: Event<SignerActionData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `refusal` events
+    async fn on_refusal(&self, _event: Event<SignerActionData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `biometric_refused` events
+    async fn on_biometric_refused(
+        &self,
+        _event: Event<SignerActionData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `attempts_by_whatsapp_exceeded` events
+    async fn on_attempts_by_whatsapp_exceeded(
+        &self,
+        _event: Event<SignerActionData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `close` events
+    async fn on_close(&self, _event: Event<UserActionData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `auto_close` events
+    async fn on_auto_close(&self, _event: Event<SystemData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `document_closed` events
+    async fn on_document_closed(&self, _event: Event<SystemData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `deadline` events
+    async fn on_deadline(&self, _event: Event<SystemData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `cancel` events
+    async fn on_cancel(&self, _event: Event<UserActionData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `update_deadline` events
+    async fn on_update_deadline(
+        &self,
+        _event: Event<UpdateDeadlineData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `update_auto_close` events
+    async fn on_update_auto_close(
+        &self,
+        _event: Event<UpdateAutoCloseData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `update_locale` events
+    async fn on_update_locale(
+        &self,
+        _event: Event<UpdateLocaleData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `add_image` events
+    async fn on_add_image(&self, _event: Event<UserActionData>, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_enqueued` events
+    async fn on_acceptance_term_enqueued(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_sent` events
+    async fn on_acceptance_term_sent(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_completed` events
+    async fn on_acceptance_term_completed(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_refused` events
+    async fn on_acceptance_term_refused(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_canceled` events
+    async fn on_acceptance_term_canceled(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_expired` events
+    async fn on_acceptance_term_expired(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on `acceptance_term_error` events
+    async fn on_acceptance_term_error(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on modeled events whose data doesn't match the expected payload, e.g. after
+    /// a change in the Clicksign API, with the raw JSON of the event and the error
+    async fn on_malformed(&self, _event: MalformedEvent, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+    /// Called on events not modeled by this crate, with the raw JSON of the event
+    async fn on_unknown(&self, _event: serde_json::Value, _document: Document) -> HandlerResult {
+        Ok(())
+    }
+}
+
+/// Given a handler and a received event, calls the handler method for the event
+async fn dispatch<H: WebhookHandler>(
+    handler: &H,
+    event: DocumentEvent,
+    document: Document,
+) -> HandlerResult {
+    match event {
+        DocumentEvent::Upload(e) => handler.on_upload(e, document).await,
+        DocumentEvent::AddSigner(e) => handler.on_add_signer(e, document).await,
+        DocumentEvent::RemoveSigner(e) => handler.on_remove_signer(e, document).await,
+        DocumentEvent::Sign(e) => handler.on_sign(e, document).await,
+        DocumentEvent::Refusal(e) => handler.on_refusal(e, document).await,
+        DocumentEvent::BiometricRefused(e) => handler.on_biometric_refused(e, document).await,
+        DocumentEvent::AttemptsByWhatsappExceeded(e) => {
+            handler.on_attempts_by_whatsapp_exceeded(e, document).await
+        }
+        DocumentEvent::Close(e) => handler.on_close(e, document).await,
+        DocumentEvent::AutoClose(e) => handler.on_auto_close(e, document).await,
+        DocumentEvent::DocumentClosed(e) => handler.on_document_closed(e, document).await,
+        DocumentEvent::Deadline(e) => handler.on_deadline(e, document).await,
+        DocumentEvent::Cancel(e) => handler.on_cancel(e, document).await,
+        DocumentEvent::UpdateDeadline(e) => handler.on_update_deadline(e, document).await,
+        DocumentEvent::UpdateAutoClose(e) => handler.on_update_auto_close(e, document).await,
+        DocumentEvent::UpdateLocale(e) => handler.on_update_locale(e, document).await,
+        DocumentEvent::AddImage(e) => handler.on_add_image(e, document).await,
+        DocumentEvent::AcceptanceTermEnqueued(e) => {
+            handler.on_acceptance_term_enqueued(e, document).await
+        }
+        DocumentEvent::AcceptanceTermSent(e) => handler.on_acceptance_term_sent(e, document).await,
+        DocumentEvent::AcceptanceTermCompleted(e) => {
+            handler.on_acceptance_term_completed(e, document).await
+        }
+        DocumentEvent::AcceptanceTermRefused(e) => {
+            handler.on_acceptance_term_refused(e, document).await
+        }
+        DocumentEvent::AcceptanceTermCanceled(e) => {
+            handler.on_acceptance_term_canceled(e, document).await
+        }
+        DocumentEvent::AcceptanceTermExpired(e) => {
+            handler.on_acceptance_term_expired(e, document).await
+        }
+        DocumentEvent::AcceptanceTermError(e) => {
+            handler.on_acceptance_term_error(e, document).await
+        }
+        DocumentEvent::Malformed(e) => handler.on_malformed(e, document).await,
+        DocumentEvent::Unknown(value) => handler.on_unknown(value, document).await,
+    }
+}
+
+struct WebhookState<H> {
+    handler: H,
+    secret: String,
+    dedupe: Option<Box<dyn DedupeStore>>,
+}
+
+/// Receives a webhook, answering:
+/// * 401 when the `Content-Hmac` header is missing or doesn't match the body;
+/// * 400 when the body can't be parsed;
+/// * 409 when the event is being handled by another delivery, so clicksign retries it;
+/// * 500 when the handler fails, so clicksign retries it;
+/// * 200 otherwise, including events already processed.
+async fn receive<H: WebhookHandler>(
+    State(state): State<Arc<WebhookState<H>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let content_hmac = match headers
+        .get(CONTENT_HMAC_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(content_hmac) => content_hmac,
+        None => return StatusCode::UNAUTHORIZED,
+    };
+    let payload = match parse_webhook(&body, content_hmac, &state.secret) {
+        Ok(payload) => payload,
+        Err(Error::InvalidSignature) => return StatusCode::UNAUTHORIZED,
+        Err(_) => return StatusCode::BAD_REQUEST,
+    };
+    let result = match &state.dedupe {
+        Some(dedupe) => {
+            process_once(dedupe.as_ref(), payload, |payload| {
+                dispatch(&state.handler, payload.event, payload.document)
+            })
+            .await
+        }
+        None => dispatch(&state.handler, payload.event, payload.document)
+            .await
+            .map(|()| Processed::Handled),
+    };
+    match result {
+        Ok(Processed::InProgress) => StatusCode::CONFLICT,
+        Ok(_) => StatusCode::OK,
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Given a handler and the webhook secret, creates a router that receives clicksign
+/// webhooks with POST requests to `/`. Use `Router::nest` to mount it in another path.
+///
+/// # Arguments
+/// * handler (WebhookHandler): Handler for the received events
+/// * secret (&str): The webhook secret, provided by clicksign
+///
+/// # Example
+/// ```no_run
+/// use clicksign::models::documents::Document;
+/// use clicksign::models::events::{Event, UserActionData};
+/// use clicksign::webhook_server::{async_trait, router, HandlerResult, WebhookHandler};
+///
+/// struct Contracts;
+///
+/// #[async_trait]
+/// impl WebhookHandler for Contracts {
+///     async fn on_close(&self, _event: Event<UserActionData>, document: Document) -> HandlerResult {
+///         println!("{:?} was finished", document.key);
+///         Ok(())
+///     }
+/// }
+///
+/// async {
+///     let app = axum::Router::new().nest("/webhooks/clicksign", router(Contracts, "some_webhook_secret"));
+///     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+///     axum::serve(listener, app).await.unwrap();
+/// };
+/// ```
+pub fn router<H: WebhookHandler>(handler: H, secret: &str) -> Router {
+    build_router(handler, secret, None)
+}
+
+/// Given a handler, the webhook secret and a dedupe store, creates a router like
+/// `router`, but calling the handler only once for each event, even when clicksign
+/// sends it more than once. Deliveries arriving while the same event is being handled
+/// are answered with 409, so clicksign sends them again if the handler fails.
+///
+/// # Arguments
+/// * handler (WebhookHandler): Handler for the received events
+/// * secret (&str): The webhook secret, provided by clicksign
+/// * dedupe (DedupeStore): Store of the events being handled or already handled
+///
+/// # Example
+/// ```no_run
+/// use clicksign::dedupe::MemoryDedupeStore;
+/// use clicksign::webhook_server::{router_with_dedupe, WebhookHandler};
+/// use std::time::Duration;
+///
+/// struct Contracts;
+/// impl WebhookHandler for Contracts {}
+///
+/// let store = MemoryDedupeStore::new(Duration::from_secs(24 * 60 * 60));
+/// let app = router_with_dedupe(Contracts, "some_webhook_secret", store);
+/// ```
+pub fn router_with_dedupe<H: WebhookHandler, S: DedupeStore + 'static>(
+    handler: H,
+    secret: &str,
+    dedupe: S,
+) -> Router {
+    build_router(handler, secret, Some(Box::new(dedupe)))
+}
+
+fn build_router<H: WebhookHandler>(
+    handler: H,
+    secret: &str,
+    dedupe: Option<Box<dyn DedupeStore>>,
+) -> Router {
+    let state = Arc::new(WebhookState {
+        handler,
+        secret: secret.to_string(),
+        dedupe,
+    });
+    Router::new().route("/", post(receive::<H>)).with_state(state)
+}
@@ -0,0 +1,147 @@
+use crate::crypto::verify_hmac_sha256_hex;
+use crate::dedupe::{Claim, DedupeStore};
+use crate::error::Error;
+use crate::models::documents::Document;
+use crate::models::events::DocumentEvent;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// Name of the header in which clicksign sends the webhook signature
+pub const CONTENT_HMAC_HEADER: &str = "Content-Hmac";
+
+/// This struct defines the body of a webhook sent by clicksign
+/// Check [clicksign docs](https://developers.clicksign.com/docs/webhooks) for detailed info.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    /// The event that triggered the webhook
+    pub event: DocumentEvent,
+    /// The document in which the event occurred
+    pub document: Document,
+}
+
+/// Given the raw body of a webhook, the value of its `Content-Hmac` header and the
+/// webhook secret, checks the body was signed by clicksign.
+/// The comparison is made in constant time.
+///
+/// # Arguments
+/// * body (&[u8]): The raw request body, exactly as received
+/// * content_hmac (&str): The `Content-Hmac` header, in the `sha256=<hex>` format
+/// * secret (&str): The webhook secret, provided by clicksign
+///
+/// # Example
+/// ```
+/// use clicksign::webhooks::verify_signature;
+///
+/// let body = br#"{"event": {}, "document": {}}"#;
+/// let content_hmac = "sha256=00000000000000000000000000000000000000000000000000000000000000";
+/// assert!(verify_signature(body, content_hmac, "some_webhook_secret").is_err());
+/// ```
+pub fn verify_signature(body: &[u8], content_hmac: &str, secret: &str) -> Result<(), Error> {
+    let hex_hmac = match content_hmac.trim().strip_prefix("sha256=") {
+        Some(hex_hmac) => hex_hmac,
+        None => return Err(Error::InvalidSignature),
+    };
+    if verify_hmac_sha256_hex(secret.as_bytes(), body, hex_hmac) {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Given the raw body of a webhook, the value of its `Content-Hmac` header and the
+/// webhook secret, verifies the signature and parses the body.
+///
+/// # Arguments
+/// * body (&[u8]): The raw request body, exactly as received
+/// * content_hmac (&str): The `Content-Hmac` header, in the `sha256=<hex>` format
+/// * secret (&str): The webhook secret, provided by clicksign
+///
+/// # Example
+/// ```no_run
+/// use clicksign::webhooks::parse_webhook;
+///
+/// # let body: &[u8] = b"";
+/// # let content_hmac = "";
+/// let payload = parse_webhook(body, content_hmac, "some_webhook_secret").unwrap();
+/// println!("{} on {:?}", payload.event.name(), payload.document.key);
+/// ```
+pub fn parse_webhook(
+    body: &[u8],
+    content_hmac: &str,
+    secret: &str,
+) -> Result<WebhookPayload, Error> {
+    verify_signature(body, content_hmac, secret)?;
+    serde_json::from_slice(body).map_err(|e| Error::decode(e, &String::from_utf8_lossy(body)))
+}
+
+/// Given a webhook payload, returns the key identifying its logical event:
+/// the document key, the event name and the datetime the event occurred.
+/// Retries of the same event by clicksign have the same key.
+pub fn idempotency_key(payload: &WebhookPayload) -> String {
+    format!(
+        "{}:{}:{}",
+        payload.document.key.as_ref().map(|key| key.as_str()).unwrap_or_default(),
+        payload.event.name(),
+        payload.event.occurred_at().unwrap_or_default()
+    )
+}
+
+/// What `process_once` did with a webhook event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Processed {
+    /// The handler was called and succeeded
+    Handled,
+    /// The event was already handled, so the handler was not called
+    Duplicate,
+    /// The event is being handled by another delivery, so the handler was not called.
+    /// The webhook must be answered with a non-2xx status, so clicksign sends it again.
+    InProgress,
+}
+
+/// Given a dedupe store, a webhook payload and a handler, calls the handler only if
+/// the event was not handled before nor is being handled by another delivery.
+/// The event is marked as completed only after the handler succeeds. When the handler
+/// fails, the event is released so it's handled again on the next retry by clicksign.
+///
+/// # Example
+/// ```no_run
+/// async {
+///   use clicksign::dedupe::MemoryDedupeStore;
+///   use clicksign::webhooks::{parse_webhook, process_once};
+///   use std::time::Duration;
+///
+///   let store = MemoryDedupeStore::new(Duration::from_secs(24 * 60 * 60));
+///   # let (body, content_hmac): (&[u8], &str) = (b"", "");
+///   let payload = parse_webhook(body, content_hmac, "some_webhook_secret").unwrap();
+///   let processed = process_once(&store, payload, |payload| async move {
+///       println!("{}", payload.event.name());
+///       Ok::<(), clicksign::Error>(())
+///   })
+///   .await
+///   .unwrap();
+/// };
+/// ```
+pub async fn process_once<S, F, Fut, E>(
+    store: &S,
+    payload: WebhookPayload,
+    handler: F,
+) -> Result<Processed, E>
+where
+    S: DedupeStore + ?Sized,
+    F: FnOnce(WebhookPayload) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: From<Error>,
+{
+    let key = idempotency_key(&payload);
+    match store.claim(&key).await? {
+        Claim::Acquired => {}
+        Claim::Pending => return Ok(Processed::InProgress),
+        Claim::Completed => return Ok(Processed::Duplicate),
+    }
+    if let Err(e) = handler(payload).await {
+        store.release(&key).await?;
+        return Err(e);
+    }
+    store.complete(&key).await?;
+    Ok(Processed::Handled)
+}
@@ -0,0 +1,56 @@
+use clicksign::client::Client;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_batch() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/batches"))
+        .and(body_json(json!({
+            "batch": {
+                "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "document_keys": [
+                    "27b02527-a576-46ee-b01c-bb4e694036c4",
+                    "ad4a9ab1-cc5f-4a5e-9cd5-9a0a9e2c8e4b"
+                ],
+                "summary": true
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "batch": {
+                "key": "3b7e8a2c-1a8f-4f7c-9a55-0b1f0c2d7e11",
+                "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "document_keys": [
+                    "27b02527-a576-46ee-b01c-bb4e694036c4",
+                    "ad4a9ab1-cc5f-4a5e-9cd5-9a0a9e2c8e4b"
+                ],
+                "summary": true,
+                "created_at": "2021-10-20T10:00:00.000-03:00",
+                "updated_at": "2021-10-20T10:00:00.000-03:00",
+                "url": "https://example.com/batches/3b7e8a2c"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let batch = client
+        .create_batch(
+            &"79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap(),
+            &[
+                "27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(),
+                "ad4a9ab1-cc5f-4a5e-9cd5-9a0a9e2c8e4b".parse().unwrap(),
+            ],
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Some("https://example.com/batches/3b7e8a2c".to_string()),
+        batch.url
+    );
+}
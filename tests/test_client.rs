@@ -1,9 +1,12 @@
 use clicksign::client::Client;
+use clicksign::environment::{ApiVersion, Environment, Url};
+use clicksign::Error;
 
 #[test]
 fn test_new_client_with_default_host() {
     let client = Client::new("c9d91ece-9b3b-4def-abac-25b645cb083c", None);
-    assert_eq!("https://app.clicksign.com/", client.host);
+    assert_eq!(Environment::Production, *client.environment());
+    assert_eq!("https://app.clicksign.com/api/v1/", client.base_url().as_str());
 }
 
 #[test]
@@ -12,7 +15,24 @@ fn test_new_client_with_no_default_host() {
         "c9d91ece-9b3b-4def-abac-25b645cb083c",
         Some("https://api.example.com"),
     );
-    assert_eq!("https://api.example.com", client.host);
+    assert_eq!(
+        Environment::Custom(Url::parse("https://api.example.com").unwrap()),
+        *client.environment()
+    );
+    assert_eq!("https://api.example.com/api/v1/", client.base_url().as_str());
+}
+
+#[test]
+fn test_sandbox_environment() {
+    let client = Client::builder("c9d91ece-9b3b-4def-abac-25b645cb083c")
+        .environment(Environment::Sandbox)
+        .api_version(ApiVersion::V1)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "https://sandbox.clicksign.com/api/v1/signers?access_token=c9d91ece-9b3b-4def-abac-25b645cb083c",
+        client.build_url("signers").as_str()
+    );
 }
 
 #[test]
@@ -23,7 +43,55 @@ fn test_build_url() {
     );
     let url = client.build_url("my-path");
     assert_eq!(
-        "https://api.example.com/my-path?access_token=c9d91ece-9b3b-4def-abac-25b645cb083c",
-        url
+        "https://api.example.com/api/v1/my-path?access_token=c9d91ece-9b3b-4def-abac-25b645cb083c",
+        url.as_str()
+    );
+}
+
+#[test]
+fn test_build_url_regardless_of_trailing_slash() {
+    for host in &["https://api.example.com/clicksign", "https://api.example.com/clicksign/"] {
+        let client = Client::new("some_access_token", Some(host));
+        assert_eq!(
+            "https://api.example.com/clicksign/api/v1/documents/some_key?access_token=some_access_token",
+            client.build_url("/documents/some_key").as_str()
+        );
+    }
+}
+
+#[test]
+fn test_new_client_with_host_without_scheme() {
+    let client = Client::new("some_access_token", Some("api.example.com"));
+    assert_eq!("https://api.example.com/api/v1/", client.base_url().as_str());
+}
+
+#[test]
+fn test_new_client_with_host_ending_with_api_version() {
+    for host in &[
+        "https://app.clicksign.com/api/v1",
+        "https://app.clicksign.com/api/v1/",
+    ] {
+        let client = Client::new("some_access_token", Some(host));
+        assert_eq!(
+            "https://app.clicksign.com/api/v1/documents?access_token=some_access_token",
+            client.build_url("documents").as_str()
+        );
+    }
+}
+
+#[test]
+fn test_build_url_encodes_access_token() {
+    let client = Client::new("some token&page=2", Some("https://api.example.com"));
+    assert_eq!(
+        "https://api.example.com/api/v1/documents?access_token=some+token%26page%3D2",
+        client.build_url("documents").as_str()
     );
 }
+
+#[test]
+fn test_invalid_host() {
+    let result = Client::builder("some_access_token").host("not a url").build();
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+    let result = Client::builder("some_access_token").host("mailto:someone@example.com").build();
+    assert!(matches!(result, Err(Error::InvalidInput(_))));
+}
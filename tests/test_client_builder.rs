@@ -0,0 +1,100 @@
+use clicksign::client::{Client, USER_AGENT};
+use clicksign::retry::RetryPolicy;
+use clicksign::Error;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{header, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DOCUMENT_KEY: &str = "27b02527-a576-46ee-b01c-bb4e694036c4";
+
+fn document() -> serde_json::Value {
+    json!({ "document": { "key": DOCUMENT_KEY, "path": "/Contratos/Contrato-123.pdf" } })
+}
+
+#[tokio::test]
+async fn test_default_user_agent_has_crate_version() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("User-Agent", USER_AGENT))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap();
+
+    assert!(USER_AGENT.ends_with(env!("CARGO_PKG_VERSION")));
+}
+
+#[tokio::test]
+async fn test_builder_sends_user_agent_and_default_headers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("User-Agent", "my-app/1.0"))
+        .and(header("X-Request-Source", "workers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder("some_access_token")
+        .host(&format!("{}/", server.uri()))
+        .user_agent("my-app/1.0")
+        .default_header(
+            HeaderName::from_static("x-request-source"),
+            HeaderValue::from_static("workers"),
+        )
+        .build()
+        .unwrap();
+    let document = client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap();
+
+    assert_eq!(DOCUMENT_KEY, document.key.unwrap().as_str());
+}
+
+#[tokio::test]
+async fn test_builder_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(document())
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&server)
+        .await;
+
+    let client = Client::builder("some_access_token")
+        .host(&format!("{}/", server.uri()))
+        .timeout(Duration::from_millis(50))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let error = client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap_err();
+
+    assert!(matches!(error, Error::Timeout(_)));
+}
+
+#[tokio::test]
+async fn test_builder_uses_given_http_client() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("User-Agent", "shared-pool"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let http_client = reqwest::Client::builder()
+        .user_agent("shared-pool")
+        .build()
+        .unwrap();
+    let client = Client::builder("some_access_token")
+        .host(&format!("{}/", server.uri()))
+        .http_client(http_client)
+        .build()
+        .unwrap();
+    client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap();
+}
@@ -0,0 +1,46 @@
+use clicksign::client::Client;
+use clicksign::crypto::{hmac_sha256_hex, sign_secret_hmac};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_hmac_sha256_rfc4231_vectors() {
+    assert_eq!(
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        hmac_sha256_hex(&[0x0b; 20], b"Hi There")
+    );
+    assert_eq!(
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        hmac_sha256_hex(b"Jefe", b"what do ya want for nothing?")
+    );
+}
+
+#[test]
+fn test_sign_secret_hmac() {
+    assert_eq!(
+        "bbf676977f2e9cb62d2ed6c05085e8799ec9e16471928f5eae7b0c81feb09e9a",
+        sign_secret_hmac("0d5a9615-2bb8-3a23-6584-33ff436bb990", "some_signer_secret")
+    );
+}
+
+#[tokio::test]
+async fn test_sign_via_api() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/sign"))
+        .and(body_json(json!({
+            "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+            "secret_hmac_sha256": "bbf676977f2e9cb62d2ed6c05085e8799ec9e16471928f5eae7b0c81feb09e9a"
+        })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    client
+        .sign_via_api(&"0d5a9615-2bb8-3a23-6584-33ff436bb990".parse().unwrap(), "some_signer_secret")
+        .await
+        .unwrap();
+}
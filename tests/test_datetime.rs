@@ -0,0 +1,141 @@
+use clicksign::datetime::{Date, DateTime};
+use clicksign::models::signers::SignerResponse;
+use serde_json::json;
+
+#[test]
+fn test_datetime_round_trip() {
+    let deadline: DateTime = "2021-12-31T14:30:59.000-03:00".parse().unwrap();
+    assert_eq!("2021-12-31T14:30:59.000-03:00", deadline.as_str());
+
+    let birthday: Date = "1983-03-31".parse().unwrap();
+    assert_eq!("1983-03-31", birthday.to_string());
+}
+
+#[test]
+fn test_model_dates_round_trip() {
+    let value = json!({
+        "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "birthday": "1983-03-31",
+        "created_at": "2021-10-20T10:00:00.000-03:00"
+    });
+    let signer: SignerResponse = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!("1983-03-31".parse().ok(), signer.birthday);
+    assert_eq!(
+        "2021-10-20T10:00:00.000-03:00".parse().ok(),
+        signer.created_at
+    );
+    assert_eq!(value, serde_json::to_value(&signer).unwrap());
+}
+
+#[test]
+fn test_invalid_dates_are_rejected_when_parsed() {
+    assert!("2021-12-31".parse::<DateTime>().is_err());
+    assert!("2021-12-31T25:00:00.000-03:00".parse::<DateTime>().is_err());
+    assert!("2021-12-31T14:30:59-0300".parse::<DateTime>().is_err());
+    assert!("31/03/1983".parse::<Date>().is_err());
+    assert!("1983-02-30".parse::<Date>().is_err());
+}
+
+#[test]
+fn test_unexpected_dates_are_kept_when_deserialized() {
+    let value = json!({
+        "birthday": "31/03/1983",
+        "created_at": "2021-10-20 10:00:00"
+    });
+    let signer: SignerResponse = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!("31/03/1983", signer.birthday.as_ref().unwrap().as_str());
+    assert_eq!(value, serde_json::to_value(&signer).unwrap());
+}
+
+#[test]
+fn test_datetimes_are_compared_by_instant() {
+    use clicksign::models::documents::{Document, DocumentFilter};
+
+    let document = Document {
+        updated_at: "2021-10-20T10:00:00.000-03:00".parse().ok(),
+        ..Default::default()
+    };
+    // Same instant as the document update, in another offset
+    let filter = DocumentFilter {
+        updated_after: "2021-10-20T13:00:00.000+00:00".parse().ok(),
+        ..Default::default()
+    };
+    assert!(filter.matches(&document));
+
+    let filter = DocumentFilter {
+        updated_after: "2021-10-20T13:00:00.001Z".parse().ok(),
+        ..Default::default()
+    };
+    assert!(!filter.matches(&document));
+
+    let filter = DocumentFilter {
+        updated_before: "2021-10-20T12:59:59.999Z".parse().ok(),
+        ..Default::default()
+    };
+    assert!(!filter.matches(&document));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_conversions() {
+    use chrono::{Datelike, Offset, Timelike};
+    use std::convert::TryFrom;
+
+    let deadline: DateTime = "2021-12-31T14:30:59.000-03:00".parse().unwrap();
+    let chrono_deadline = chrono::DateTime::try_from(&deadline).unwrap();
+    assert_eq!(
+        -3 * 60 * 60,
+        chrono_deadline.offset().fix().local_minus_utc()
+    );
+    assert_eq!(14, chrono_deadline.hour());
+    assert_eq!(deadline, DateTime::from(chrono_deadline));
+
+    let birthday: Date = "1983-03-31".parse().unwrap();
+    let chrono_birthday = chrono::NaiveDate::try_from(&birthday).unwrap();
+    assert_eq!(
+        (1983, 3, 31),
+        (
+            chrono_birthday.year(),
+            chrono_birthday.month(),
+            chrono_birthday.day()
+        )
+    );
+    assert_eq!(birthday, Date::from(chrono_birthday));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_conversions() {
+    use std::convert::TryFrom;
+
+    let deadline: DateTime = "2021-12-31T14:30:59.000-03:00".parse().unwrap();
+    let time_deadline = time::OffsetDateTime::try_from(&deadline).unwrap();
+    assert_eq!(-3 * 60 * 60, time_deadline.offset().whole_seconds());
+    assert_eq!(14, time_deadline.hour());
+    assert_eq!(deadline, DateTime::from(time_deadline));
+
+    let birthday: Date = "1983-03-31".parse().unwrap();
+    let time_birthday = time::Date::try_from(&birthday).unwrap();
+    assert_eq!(
+        (1983, time::Month::March, 31),
+        (
+            time_birthday.year(),
+            time_birthday.month(),
+            time_birthday.day()
+        )
+    );
+    assert_eq!(birthday, Date::from(time_birthday));
+}
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+#[test]
+fn test_chrono_and_time_together() {
+    use std::convert::TryFrom;
+
+    let deadline: DateTime = "2021-12-31T14:30:59.000-03:00".parse().unwrap();
+    let chrono_deadline = chrono::DateTime::try_from(&deadline).unwrap();
+    let time_deadline = time::OffsetDateTime::try_from(&deadline).unwrap();
+    assert_eq!(chrono_deadline.timestamp(), time_deadline.unix_timestamp());
+}
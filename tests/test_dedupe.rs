@@ -0,0 +1,146 @@
+use clicksign::dedupe::{Claim, DedupeStore, FileDedupeStore, MemoryDedupeStore};
+use clicksign::webhooks::{idempotency_key, process_once, Processed, WebhookPayload};
+use clicksign::Error;
+use serde_json::json;
+use std::time::Duration;
+
+fn payload() -> WebhookPayload {
+    serde_json::from_value(json!({
+        "event": {
+            "name": "close",
+            "data": {},
+            "occurred_at": "2021-10-21T10:00:00.000-03:00"
+        },
+        "document": {
+            "key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+            "path": "/Contratos/Contrato-123.pdf"
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_idempotency_key() {
+    assert_eq!(
+        "27b02527-a576-46ee-b01c-bb4e694036c4:close:2021-10-21T10:00:00.000-03:00",
+        idempotency_key(&payload())
+    );
+}
+
+#[tokio::test]
+async fn test_memory_store_claims_once_until_expired() {
+    let store = MemoryDedupeStore::new(Duration::from_millis(50));
+
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+    assert_eq!(Claim::Pending, store.claim("a").await.unwrap());
+    store.complete("a").await.unwrap();
+    assert_eq!(Claim::Completed, store.claim("a").await.unwrap());
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_memory_store_pending_claim_expires_after_lease() {
+    let store =
+        MemoryDedupeStore::new(Duration::from_secs(60)).with_lease(Duration::from_millis(50));
+
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+    assert_eq!(Claim::Pending, store.claim("a").await.unwrap());
+    // The handler died without completing nor releasing the key
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_file_store_keeps_keys_across_reopen() {
+    let path = std::env::temp_dir().join(format!("clicksign-dedupe-{}", std::process::id()));
+    let ttl = Duration::from_secs(60);
+
+    let store = FileDedupeStore::open(&path, ttl).await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+    store.complete("a").await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("b").await.unwrap());
+    store.release("b").await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("c").await.unwrap());
+
+    let store = FileDedupeStore::open(&path, ttl).await.unwrap();
+    assert_eq!(Claim::Completed, store.claim("a").await.unwrap());
+    assert_eq!(Claim::Acquired, store.claim("b").await.unwrap());
+    assert_eq!(Claim::Pending, store.claim("c").await.unwrap());
+
+    // Simulates a crash while handling "c", reopening after its lease expired
+    let store = FileDedupeStore::open(&path, ttl)
+        .await
+        .unwrap()
+        .with_lease(Duration::from_secs(0));
+    assert_eq!(Claim::Acquired, store.claim("d").await.unwrap());
+    let store = FileDedupeStore::open(&path, ttl).await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("d").await.unwrap());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_file_store_ignores_interrupted_rewrite() {
+    let path =
+        std::env::temp_dir().join(format!("clicksign-dedupe-rewrite-{}", std::process::id()));
+    let temp_path = path.with_file_name(format!(
+        "{}.tmp",
+        path.file_name().unwrap().to_str().unwrap()
+    ));
+    let ttl = Duration::from_secs(60);
+
+    let store = FileDedupeStore::open(&path, ttl).await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("a").await.unwrap());
+    store.complete("a").await.unwrap();
+    assert_eq!(Claim::Acquired, store.claim("b").await.unwrap());
+    store.release("b").await.unwrap();
+    assert!(!temp_path.exists());
+
+    // Simulates a crash in the middle of a rewrite, leaving a partial temporary file
+    std::fs::write(&temp_path, "1234").unwrap();
+    let store = FileDedupeStore::open(&path, ttl).await.unwrap();
+    assert_eq!(Claim::Completed, store.claim("a").await.unwrap());
+    assert!(!temp_path.exists());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_process_once() {
+    let store = MemoryDedupeStore::new(Duration::from_secs(60));
+
+    let failed = process_once(&store, payload(), |_| async {
+        Err::<(), Error>(Error::InvalidInput("database is down".to_string()))
+    })
+    .await;
+    assert!(failed.is_err());
+
+    let processed = process_once(&store, payload(), |_| async { Ok::<(), Error>(()) }).await;
+    assert_eq!(Processed::Handled, processed.unwrap());
+
+    let processed = process_once(&store, payload(), |_| async { Ok::<(), Error>(()) }).await;
+    assert_eq!(Processed::Duplicate, processed.unwrap());
+}
+
+#[tokio::test]
+async fn test_process_once_concurrent_deliveries_when_first_fails() {
+    let store = MemoryDedupeStore::new(Duration::from_secs(60));
+
+    let first = process_once(&store, payload(), |_| async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Err::<(), Error>(Error::InvalidInput("database is down".to_string()))
+    });
+    let second = async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        process_once(&store, payload(), |_| async { Ok::<(), Error>(()) }).await
+    };
+    let (first, second) = tokio::join!(first, second);
+
+    assert!(first.is_err());
+    // The retry must not be acknowledged while the first delivery may still fail
+    assert_eq!(Processed::InProgress, second.unwrap());
+
+    let processed = process_once(&store, payload(), |_| async { Ok::<(), Error>(()) }).await;
+    assert_eq!(Processed::Handled, processed.unwrap());
+}
@@ -0,0 +1,277 @@
+use clicksign::client::Client;
+use clicksign::models::documents::{
+    Document, DocumentConfiguration, DocumentFilter, DocumentTemplate, NewDocument,
+};
+use clicksign::models::enums::DocumentStatus;
+use clicksign::models::events::DocumentEvent;
+use clicksign::Error;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::collections::HashMap;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn document_response() -> serde_json::Value {
+    json!({
+        "document": {
+            "key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+            "path": "/Contratos/Contrato-123.pdf",
+            "filename": "Contrato-123.pdf",
+            "uploaded_at": "2021-10-20T10:00:00.000-03:00",
+            "updated_at": "2021-10-20T10:00:00.000-03:00",
+            "finished_at": null,
+            "deadline_at": "2021-12-31T14:30:59.000-03:00",
+            "status": "running",
+            "auto_close": true,
+            "locale": "pt-BR",
+            "metadata": {},
+            "sequence_enabled": false,
+            "signable_group": null,
+            "remind_interval": null
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_create_document_by_upload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/documents"))
+        .and(query_param("access_token", "some_access_token"))
+        .and(body_json(json!({
+            "document": {
+                "path": "/Contratos/Contrato-123.pdf",
+                "content_base64": "data:application/pdf;base64,JVBERi0xLjQ=",
+                "auto_close": true
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(document_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let document = NewDocument::new("/Contratos/Contrato-123.pdf").auto_close(true);
+    let document = client
+        .create_document_by_upload_from_reader(document, &b"%PDF-1.4"[..])
+        .await
+        .unwrap();
+
+    assert_eq!("27b02527-a576-46ee-b01c-bb4e694036c4", document.key.unwrap());
+    assert!(document.template.is_none());
+}
+
+#[tokio::test]
+async fn test_get_document() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/documents/27b02527-a576-46ee-b01c-bb4e694036c4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "document": {
+                "key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+                "path": "/Contratos/Contrato-123.pdf",
+                "status": "running",
+                "downloads": {
+                    "original_file_url": "https://example.com/original.pdf",
+                    "signed_file_url": null
+                },
+                "signers": [{
+                    "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                    "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+                    "list_key": "7ee5ab51-98b6-4d10-a5f5-4fb7e8b7b1e1",
+                    "email": "fulano@example.com",
+                    "sign_as": "sign",
+                    "auths": ["email"],
+                    "name": "Marcos Zumba",
+                    "has_documentation": true,
+                    "signature": {
+                        "name": "Marcos Zumba",
+                        "validation": { "status": "conferred", "name": "Marcos Zumba" },
+                        "signed_at": "2021-10-21T10:00:00.000-03:00"
+                    }
+                }],
+                "events": [
+                    {
+                        "name": "upload",
+                        "data": {
+                            "user": { "email": "admin@example.com", "name": "Admin" },
+                            "account": { "key": "a1b2c3" },
+                            "deadline_at": "2021-12-31T14:30:59.000-03:00",
+                            "auto_close": true,
+                            "locale": "pt-BR"
+                        },
+                        "occurred_at": "2021-10-20T10:00:00.000-03:00"
+                    },
+                    {
+                        "name": "add_signer",
+                        "data": {
+                            "user": { "email": "admin@example.com", "name": "Admin" },
+                            "signers": [{ "key": "79301388-9567-4320-90ce-9e6f60e70d28" }]
+                        },
+                        "occurred_at": "2021-10-20T10:01:00.000-03:00"
+                    }
+                ],
+                "lists": [{
+                    "key": "7ee5ab51-98b6-4d10-a5f5-4fb7e8b7b1e1",
+                    "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+                    "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+                    "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                    "sign_as": "sign",
+                    "group": 1,
+                    "url": "https://example.com/sign/0d5a9615"
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let document = client
+        .get_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+        .await
+        .unwrap();
+
+    let signers = document.signers.unwrap();
+    assert_eq!("79301388-9567-4320-90ce-9e6f60e70d28", signers[0].key);
+    assert!(signers[0].signature.is_some());
+    let events = document.events.unwrap();
+    assert_eq!(2, events.len());
+    assert!(matches!(&events[0], DocumentEvent::Upload(e) if e.data.deadline_at.is_some()));
+    match &events[1] {
+        DocumentEvent::AddSigner(event) => assert_eq!(1, event.data.signers.as_ref().unwrap().len()),
+        event => panic!("Unexpected event: {:?}", event),
+    }
+    assert_eq!(1, document.lists.unwrap().len());
+    assert!(document.downloads.unwrap().signed_file_url.is_none());
+}
+
+#[tokio::test]
+async fn test_documents_stream_across_pages() {
+    let server = MockServer::start().await;
+    for (page, status) in [(1, "running"), (2, "closed"), (3, "closed")].iter() {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/documents"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "documents": [
+                    { "key": format!("00000000-0000-0000-0000-00000000000{}", page), "path": "/Contratos/a.pdf", "status": status },
+                    { "key": format!("00000000-0000-0000-0000-00000000010{}", page), "path": "/Outros/b.pdf", "status": status }
+                ],
+                "page_infos": { "current_page": page, "last_page": 3 }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let filter = DocumentFilter {
+        status: Some(DocumentStatus::Closed),
+        folder: Some("/Contratos/".to_string()),
+        ..Default::default()
+    };
+    let documents: Vec<Document> = client
+        .documents_stream(filter, 2)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let keys: Vec<_> = documents.into_iter().map(|d| d.key.unwrap()).collect();
+    assert_eq!(
+        vec![
+            "00000000-0000-0000-0000-000000000002",
+            "00000000-0000-0000-0000-000000000003"
+        ],
+        keys
+    );
+}
+
+#[tokio::test]
+async fn test_configure_document_sends_only_given_settings() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/api/v1/documents/27b02527-a576-46ee-b01c-bb4e694036c4"))
+        .and(body_json(json!({ "document": { "auto_close": false } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let configuration = DocumentConfiguration {
+        auto_close: Some(false),
+        ..Default::default()
+    };
+    client
+        .configure_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap(), configuration)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_document_surfaces_validation_message() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/api/v1/documents/27b02527-a576-46ee-b01c-bb4e694036c4/cancel"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(json!({ "errors": ["Documento já está finalizado"] })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let error = client
+        .cancel_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("Documento já está finalizado"));
+}
+
+#[tokio::test]
+async fn test_create_document_by_model_wraps_the_document() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/templates/e4c6b5b1-3a5c-4a42-9c1e-6f1b0d7c2a10/documents"))
+        .and(body_json(json!({
+            "document": {
+                "path": "/Modelos/Teste-123.docx",
+                "template": {
+                    "key": "e4c6b5b1-3a5c-4a42-9c1e-6f1b0d7c2a10",
+                    "data": { "Company Name": "Clicksign" }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(document_response()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let mut data = HashMap::new();
+    data.insert("Company Name".to_string(), "Clicksign".to_string());
+    let document = Document {
+        path: "/Modelos/Teste-123.docx".to_string(),
+        template: Some(DocumentTemplate {
+            key: "e4c6b5b1-3a5c-4a42-9c1e-6f1b0d7c2a10".parse().unwrap(),
+            data,
+        }),
+        ..Default::default()
+    };
+    let document = client.create_document_by_model(document).await.unwrap();
+
+    assert_eq!("27b02527-a576-46ee-b01c-bb4e694036c4", document.key.unwrap());
+}
+
+#[tokio::test]
+async fn test_create_document_by_model_requires_template() {
+    let client = Client::new("some_access_token", Some("http://127.0.0.1:9/"));
+    let document = Document {
+        path: "/Modelos/Teste-123.docx".to_string(),
+        ..Default::default()
+    };
+    let error = client.create_document_by_model(document).await.unwrap_err();
+
+    assert!(matches!(error, Error::InvalidInput(_)));
+}
@@ -0,0 +1,47 @@
+use clicksign::models::enums::{AuthMethod, DocumentStatus, Locale, SignAs};
+use clicksign::models::signers::SignerToDocument;
+use serde_json::json;
+
+#[test]
+fn test_known_values_round_trip() {
+    let auths: Vec<AuthMethod> =
+        serde_json::from_value(json!(["email", "whatsapp", "icp_brasil", "facial_biometrics"])).unwrap();
+    assert_eq!(
+        vec![
+            AuthMethod::Email,
+            AuthMethod::Whatsapp,
+            AuthMethod::IcpBrasil,
+            AuthMethod::FacialBiometrics
+        ],
+        auths
+    );
+    assert_eq!(
+        json!(["email", "whatsapp", "icp_brasil", "facial_biometrics"]),
+        serde_json::to_value(&auths).unwrap()
+    );
+    assert_eq!(json!("pt-BR"), serde_json::to_value(Locale::PtBr).unwrap());
+    assert_eq!(Locale::EnUs, serde_json::from_value(json!("en-US")).unwrap());
+}
+
+#[test]
+fn test_unknown_values_are_kept() {
+    let status: DocumentStatus = serde_json::from_value(json!("draft")).unwrap();
+    assert_eq!(DocumentStatus::Other("draft".to_string()), status);
+    assert_eq!(json!("draft"), serde_json::to_value(&status).unwrap());
+
+    let list: SignerToDocument = serde_json::from_value(json!({
+        "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+        "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "sign_as": "some_new_role"
+    }))
+    .unwrap();
+    assert_eq!(SignAs::Other("some_new_role".to_string()), list.sign_as);
+}
+
+#[test]
+fn test_parse_and_display() {
+    let sign_as: SignAs = "witness".parse().unwrap();
+    assert_eq!(SignAs::Witness, sign_as);
+    assert_eq!("witness", sign_as.to_string());
+    assert_eq!(AuthMethod::Other("wahtsapp".to_string()), AuthMethod::from("wahtsapp"));
+}
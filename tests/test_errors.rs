@@ -0,0 +1,78 @@
+use clicksign::client::Client;
+use clicksign::retry::RetryPolicy;
+use clicksign::Error;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DOCUMENT_KEY: &str = "27b02527-a576-46ee-b01c-bb4e694036c4";
+
+async fn get_document_with(response: ResponseTemplate) -> Error {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/documents/{}", DOCUMENT_KEY)))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy::none());
+    client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap_err()
+}
+
+#[test]
+fn test_error_is_send_sync_static() {
+    fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+    assert_send_sync_static::<Error>();
+}
+
+#[tokio::test]
+async fn test_validation_error_parses_errors() {
+    let error = get_document_with(
+        ResponseTemplate::new(422).set_body_json(json!({ "errors": ["Prazo inválido"] })),
+    )
+    .await;
+
+    match error {
+        Error::Validation { status, errors, .. } => {
+            assert_eq!(422, status.as_u16());
+            assert_eq!(vec!["Prazo inválido".to_string()], errors);
+        }
+        error => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_unauthorized_error() {
+    let error = get_document_with(ResponseTemplate::new(401)).await;
+    assert!(matches!(error, Error::Unauthorized));
+}
+
+#[tokio::test]
+async fn test_server_error() {
+    let error = get_document_with(ResponseTemplate::new(503).set_body_string("down")).await;
+    match error {
+        Error::Server { status, body } => {
+            assert_eq!(503, status.as_u16());
+            assert_eq!("down", body);
+        }
+        error => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_decode_error_keeps_raw_body() {
+    let error = get_document_with(ResponseTemplate::new(200).set_body_string("<html>")).await;
+    match error {
+        Error::Decode { body, .. } => assert_eq!("<html>", body),
+        error => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn test_client_future_can_be_spawned() {
+    let server = MockServer::start().await;
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let handle = tokio::spawn(async move { client.get_document(&DOCUMENT_KEY.parse().unwrap()).await });
+    assert!(handle.await.unwrap().is_err());
+}
@@ -0,0 +1,109 @@
+use clicksign::models::documents::Document;
+use clicksign::models::events::DocumentEvent;
+use serde_json::json;
+
+#[test]
+fn test_sign_event() {
+    let event: DocumentEvent = serde_json::from_value(json!({
+        "name": "sign",
+        "data": {
+            "account": { "key": "a1b2c3" },
+            "signer": {
+                "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "email": "fulano@example.com",
+                "sign_as": "sign",
+                "auths": ["email"]
+            }
+        },
+        "occurred_at": "2021-10-21T10:00:00.000-03:00"
+    }))
+    .unwrap();
+
+    match &event {
+        DocumentEvent::Sign(event) => assert_eq!(
+            Some("fulano@example.com".to_string()),
+            event.data.signer.as_ref().unwrap().email
+        ),
+        event => panic!("Unexpected event: {:?}", event),
+    }
+    assert_eq!("sign", event.name());
+    assert_eq!(Some("2021-10-21T10:00:00.000-03:00"), event.occurred_at());
+}
+
+#[test]
+fn test_unknown_event_keeps_raw_json() {
+    let value = json!({
+        "name": "some_new_event",
+        "data": { "anything": [1, 2, 3] },
+        "occurred_at": "2021-10-21T10:00:00.000-03:00"
+    });
+    let event: DocumentEvent = serde_json::from_value(value.clone()).unwrap();
+
+    assert!(matches!(&event, DocumentEvent::Unknown(raw) if raw == &value));
+    assert_eq!("some_new_event", event.name());
+    assert_eq!(value, serde_json::to_value(&event).unwrap());
+}
+
+#[test]
+fn test_known_event_round_trip() {
+    let value = json!({
+        "name": "update_deadline",
+        "data": {
+            "user": { "email": "admin@example.com", "name": "Admin" },
+            "account": { "key": "a1b2c3" },
+            "deadline_at": "2021-12-31T14:30:59.000-03:00"
+        },
+        "occurred_at": "2021-10-21T10:00:00.000-03:00"
+    });
+    let event: DocumentEvent = serde_json::from_value(value.clone()).unwrap();
+
+    assert!(matches!(event, DocumentEvent::UpdateDeadline(_)));
+    assert_eq!(value, serde_json::to_value(&event).unwrap());
+}
+
+#[test]
+fn test_malformed_known_event_keeps_raw_json() {
+    let value = json!({
+        "name": "close",
+        "data": { "user": "not an object" },
+        "occurred_at": "2021-10-21T10:00:00.000-03:00"
+    });
+    let event: DocumentEvent = serde_json::from_value(value.clone()).unwrap();
+
+    match &event {
+        DocumentEvent::Malformed(event) => {
+            assert_eq!("close", event.name);
+            assert_eq!(value, event.raw);
+            assert!(!event.error.is_empty());
+        }
+        event => panic!("Unexpected event: {:?}", event),
+    }
+    assert_eq!("close", event.name());
+    assert_eq!(Some("2021-10-21T10:00:00.000-03:00"), event.occurred_at());
+    assert_eq!(value, serde_json::to_value(&event).unwrap());
+}
+
+#[test]
+fn test_malformed_known_event_does_not_break_document() {
+    let document: Document = serde_json::from_value(json!({
+        "key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+        "path": "/a.pdf",
+        "events": [
+            {
+                "name": "sign",
+                "data": { "signer": { "key": "not a uuid" } },
+                "occurred_at": "2021-10-21T10:00:00.000-03:00"
+            },
+            {
+                "name": "close",
+                "data": {},
+                "occurred_at": "2021-10-21T11:00:00.000-03:00"
+            }
+        ]
+    }))
+    .unwrap();
+
+    let events = document.events.unwrap();
+    assert!(matches!(&events[0], DocumentEvent::Malformed(event) if event.name == "sign"));
+    assert!(matches!(events[1], DocumentEvent::Close(_)));
+}
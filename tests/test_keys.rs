@@ -0,0 +1,39 @@
+use clicksign::models::keys::{DocumentKey, SignerKey};
+use clicksign::models::signers::SignerToDocument;
+use clicksign::Error;
+use serde_json::json;
+
+#[test]
+fn test_parse_validates_uuid() {
+    assert!(DocumentKey::parse("27b02527-a576-46ee-b01c-bb4e694036c4").is_ok());
+    assert!(DocumentKey::parse("27B02527-A576-46EE-B01C-BB4E694036C4").is_ok());
+    for invalid in &[
+        "",
+        "Contrato-123",
+        "27b02527a57646eeb01cbb4e694036c4",
+        "27b02527-a576-46ee-b01c-bb4e694036c",
+        "27b02527-a576-46ee-b01c-bb4e694036cg",
+    ] {
+        assert!(matches!(DocumentKey::parse(invalid), Err(Error::InvalidInput(_))));
+    }
+}
+
+#[test]
+fn test_keys_serialize_transparently() {
+    let key: SignerKey = "79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap();
+    assert_eq!(
+        json!("79301388-9567-4320-90ce-9e6f60e70d28"),
+        serde_json::to_value(&key).unwrap()
+    );
+    assert_eq!(key, serde_json::from_value::<SignerKey>(json!(key.as_str())).unwrap());
+}
+
+#[test]
+fn test_deserialize_rejects_invalid_keys() {
+    let result: Result<SignerToDocument, _> = serde_json::from_value(json!({
+        "document_key": "not-a-key",
+        "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "sign_as": "sign"
+    }));
+    assert!(result.unwrap_err().to_string().contains("Invalid document key"));
+}
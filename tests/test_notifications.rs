@@ -0,0 +1,168 @@
+use clicksign::client::Client;
+use clicksign::models::notifications::{Notification, NotificationChannel};
+use clicksign::models::signers::{SignerResponse, SignerToDocument};
+use clicksign::Error;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn signer(phone_number: &str) -> SignerResponse {
+    serde_json::from_value(json!({
+        "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "email": "fulano@example.com",
+        "phone_number": phone_number,
+        "auths": ["whatsapp"],
+        "name": "Marcos Zumba",
+        "documentation": "123.321.123-40",
+        "birthday": "1983-03-31",
+        "has_documentation": true,
+        "delivery": "email",
+        "selfie_enabled": false,
+        "handwritten_enabled": false,
+        "official_document_enabled": false,
+        "liveness_enabled": false
+    }))
+    .unwrap()
+}
+
+fn list() -> SignerToDocument {
+    serde_json::from_value(json!({
+        "key": "7ee5ab51-98b6-4d10-a5f5-4fb7e8b7b1e1",
+        "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+        "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+        "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "sign_as": "sign"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_request_signing_by_whatsapp() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/notify_by_whatsapp"))
+        .and(body_json(json!({
+            "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990"
+        })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    client
+        .request_signing_by_whatsapp(&list(), &signer("11999999999"))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_request_signing_by_sms_rejects_invalid_phone_number() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let invalid = [
+        ("", "is required for SMS and Whatsapp notifications"),
+        (
+            "1199999999",
+            "must be a mobile number with 11 digits, e.g. 11999999999",
+        ),
+        (
+            "11333333333",
+            "must be a mobile number with 11 digits, e.g. 11999999999",
+        ),
+    ];
+    for (phone_number, message) in invalid.iter() {
+        match client
+            .request_signing_by_sms(&list(), &signer(phone_number))
+            .await
+        {
+            Err(Error::InvalidFields(errors)) => {
+                assert_eq!(1, errors.len());
+                assert_eq!("phone_number", errors[0].field);
+                assert_eq!(*message, errors[0].message);
+            }
+            other => panic!(
+                "expected invalid fields for {}, got {:?}",
+                phone_number, other
+            ),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_request_signing_by_sms_accepts_formatted_phone_number() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/notify_by_sms"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    for phone_number in ["(11) 99999-9999", "+55 11 99999-9999"].iter() {
+        client
+            .request_signing_by_sms(&list(), &signer(phone_number))
+            .await
+            .unwrap();
+    }
+}
+
+fn notification() -> Notification {
+    Notification {
+        request_signature_key: "0d5a9615-2bb8-3a23-6584-33ff436bb990".parse().unwrap(),
+        message: Some("Prezado, seu documento já está disponível para assinatura".to_string()),
+        url: None,
+    }
+}
+
+#[tokio::test]
+async fn test_request_signing_by_email() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/notifications"))
+        .and(body_json(json!({
+            "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+            "message": "Prezado, seu documento já está disponível para assinatura"
+        })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let result = client
+        .request_signing_by_email(&notification())
+        .await
+        .unwrap();
+
+    assert_eq!(NotificationChannel::Email, result.channel);
+    assert_eq!(
+        "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+        result.request_signature_key
+    );
+}
+
+#[tokio::test]
+async fn test_request_signing_by_email_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/notifications"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let error = client
+        .request_signing_by_email(&notification())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Unauthorized));
+}
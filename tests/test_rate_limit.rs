@@ -0,0 +1,129 @@
+use clicksign::client::Client;
+use clicksign::models::documents::DocumentConfiguration;
+use clicksign::rate_limit::RateLimiter;
+use clicksign::retry::RetryPolicy;
+use clicksign::Error;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DOCUMENT_KEY: &str = "27b02527-a576-46ee-b01c-bb4e694036c4";
+
+fn document() -> serde_json::Value {
+    json!({ "document": { "key": DOCUMENT_KEY, "path": "/Contratos/Contrato-123.pdf" } })
+}
+
+#[tokio::test]
+async fn test_rate_limited_exposes_retry_after() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy::none());
+    let error = client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::RateLimited { retry_after: Some(retry_after) } if retry_after == Duration::from_secs(7)
+    ));
+}
+
+#[tokio::test]
+async fn test_rate_limited_reads_reset_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(429).insert_header("X-RateLimit-Reset", "3"))
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy::none());
+    let error = client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::RateLimited { retry_after: Some(retry_after) } if retry_after == Duration::from_secs(3)
+    ));
+}
+
+#[tokio::test]
+async fn test_rate_limited_patch_is_sent_again_with_limiter() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_rate_limiter(RateLimiter::new(10, 10, Duration::from_secs(1)));
+    let document = client
+        .configure_document(&DOCUMENT_KEY.parse().unwrap(), DocumentConfiguration::default())
+        .await
+        .unwrap();
+
+    assert_eq!(DOCUMENT_KEY, document.key.unwrap().as_str());
+}
+
+#[tokio::test]
+async fn test_limiter_paces_requests() {
+    let limiter = RateLimiter::new(1, 10, Duration::from_secs(1));
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.acquire().await;
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(190));
+}
+
+#[tokio::test]
+async fn test_paused_limiter_waits() {
+    let limiter = RateLimiter::new(5, 5, Duration::from_secs(1));
+    limiter.pause(Duration::from_millis(100)).await;
+    let start = Instant::now();
+    limiter.acquire().await;
+
+    assert!(start.elapsed() >= Duration::from_millis(90));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_pause_is_capped_by_max_delay() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "86400"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy {
+            max_delay: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .with_rate_limiter(RateLimiter::new(10, 10, Duration::from_secs(1)));
+    let document = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.get_document(&DOCUMENT_KEY.parse().unwrap()),
+    )
+    .await
+    .expect("Retry-After should be capped by max_delay")
+    .unwrap();
+
+    assert_eq!(DOCUMENT_KEY, document.key.unwrap().as_str());
+}
@@ -0,0 +1,138 @@
+use clicksign::client::Client;
+use clicksign::models::documents::DocumentConfiguration;
+use clicksign::retry::RetryPolicy;
+use clicksign::Error;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DOCUMENT_KEY: &str = "27b02527-a576-46ee-b01c-bb4e694036c4";
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        ..Default::default()
+    }
+}
+
+fn document() -> serde_json::Value {
+    json!({ "document": { "key": DOCUMENT_KEY, "path": "/Contratos/Contrato-123.pdf" } })
+}
+
+#[tokio::test]
+async fn test_get_is_retried_until_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(policy());
+    let document = client.get_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap();
+
+    assert_eq!(DOCUMENT_KEY, document.key.unwrap().as_str());
+}
+
+#[tokio::test]
+async fn test_final_error_exposes_attempts() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(policy());
+    let error = client.delete_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap_err();
+
+    assert_eq!(3, error.attempts());
+    assert!(matches!(error.last_error(), Error::Server { .. }));
+}
+
+#[tokio::test]
+async fn test_non_idempotent_requests_are_not_retried_by_default() {
+    let server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/api/v1/documents/{}", DOCUMENT_KEY)))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(policy());
+    let error = client
+        .configure_document(&DOCUMENT_KEY.parse().unwrap(), DocumentConfiguration::default())
+        .await
+        .unwrap_err();
+
+    assert_eq!(1, error.attempts());
+}
+
+#[tokio::test]
+async fn test_non_idempotent_requests_retried_when_enabled() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/v1/documents/{}/duplicate", DOCUMENT_KEY)))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/api/v1/documents/{}/duplicate", DOCUMENT_KEY)))
+        .respond_with(ResponseTemplate::new(201).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy {
+            retry_non_idempotent: true,
+            ..policy()
+        });
+
+    client.duplicate_document(&DOCUMENT_KEY.parse().unwrap()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_retry_after_is_capped_by_max_delay() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "86400"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(document()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())))
+        .with_retry_policy(RetryPolicy {
+            max_delay: Duration::from_millis(10),
+            ..policy()
+        });
+    let document = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.get_document(&DOCUMENT_KEY.parse().unwrap()),
+    )
+    .await
+    .expect("Retry-After should be capped by max_delay")
+    .unwrap();
+
+    assert_eq!(DOCUMENT_KEY, document.key.unwrap().as_str());
+}
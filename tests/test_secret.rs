@@ -0,0 +1,39 @@
+use clicksign::client::Client;
+use clicksign::retry::RetryPolicy;
+use clicksign::secret::AccessToken;
+
+const ACCESS_TOKEN: &str = "c9d91ece-9b3b-4def-abac-25b645cb083c";
+
+#[test]
+fn test_access_token_is_redacted() {
+    let token = AccessToken::from(ACCESS_TOKEN);
+    assert_eq!("AccessToken([REDACTED])", format!("{:?}", token));
+    assert_eq!("[REDACTED]", token.to_string());
+    assert_eq!(ACCESS_TOKEN, token.expose_secret());
+}
+
+#[test]
+fn test_client_debug_hides_access_token() {
+    let client = Client::new(ACCESS_TOKEN, Some("https://api.example.com"));
+    let debug = format!("{:?}", client);
+    assert!(!debug.contains(ACCESS_TOKEN));
+    assert!(debug.contains("[REDACTED]"));
+}
+
+#[tokio::test]
+async fn test_transport_error_masks_access_token() {
+    // Nothing listens on the discard port, so the connection is refused
+    let client = Client::builder(ACCESS_TOKEN)
+        .host("http://127.0.0.1:9")
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let error = client
+        .get_document(&"27b02527-a576-46ee-b01c-bb4e694036c4".parse().unwrap())
+        .await
+        .unwrap_err();
+
+    assert!(!error.to_string().contains(ACCESS_TOKEN));
+    assert!(!format!("{:?}", error).contains(ACCESS_TOKEN));
+    assert!(error.to_string().contains("access_token=%5BREDACTED%5D"));
+}
@@ -0,0 +1,176 @@
+use clicksign::client::Client;
+use clicksign::models::enums::AuthMethod;
+use clicksign::models::signers::{NewSigner, SignerResponse};
+use clicksign::Error;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_remove_signer_from_document_with_list_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/lists"))
+        .and(body_json(json!({
+            "list": {
+                "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+                "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "sign_as": "sign",
+                "message": "Por favor, assine o documento."
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "list": {
+                "key": "7ee5ab51-98b6-4d10-a5f5-4fb7e8b7b1e1",
+                "request_signature_key": "0d5a9615-2bb8-3a23-6584-33ff436bb990",
+                "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+                "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "sign_as": "sign",
+                "message": "Por favor, assine o documento."
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/lists/7ee5ab51-98b6-4d10-a5f5-4fb7e8b7b1e1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let list = serde_json::from_value(json!({
+        "document_key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+        "signer_key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "sign_as": "sign",
+        "message": "Por favor, assine o documento."
+    }))
+    .unwrap();
+    let list = client.add_signer_to_document(list).await.unwrap();
+    let list_key = list.key.as_ref().unwrap();
+
+    client.remove_signer_from_document(list_key).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_signer_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/signers/79301388-9567-4320-90ce-9e6f60e70d28"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let error = client
+        .delete_signer(&"79301388-9567-4320-90ce-9e6f60e70d28".parse().unwrap())
+        .await
+        .unwrap_err();
+
+    assert_eq!("404 Not Found", error.to_string());
+}
+
+#[tokio::test]
+async fn test_create_signer_sends_only_set_fields() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/signers"))
+        .and(body_json(json!({
+            "signer": {
+                "email": "john@example.com",
+                "auths": ["email"],
+                "name": "John Doe",
+                "has_documentation": false
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "signer": {
+                "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+                "email": "john@example.com",
+                "auths": ["email"],
+                "name": "John Doe",
+                "has_documentation": false
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let signer = NewSigner::new("John Doe", "john@example.com").without_documentation();
+    let signer = client.create_signer(signer).await.unwrap();
+
+    assert_eq!("79301388-9567-4320-90ce-9e6f60e70d28", signer.key.unwrap());
+    assert_eq!(None, signer.documentation);
+}
+
+#[tokio::test]
+async fn test_create_signer_normalizes_fields() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/signers"))
+        .and(body_json(json!({
+            "signer": {
+                "email": "fulano@example.com",
+                "phone_number": "11999999999",
+                "auths": ["sms"],
+                "name": "Marcos Zumba",
+                "documentation": "123.321.123-40"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "signer": { "key": "79301388-9567-4320-90ce-9e6f60e70d28" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let signer = NewSigner {
+        phone_number: Some("+55 (11) 99999-9999".to_string()),
+        auths: vec![AuthMethod::Sms],
+        documentation: Some("12332112340".to_string()),
+        ..NewSigner::new("Marcos Zumba", "fulano@example.com")
+    };
+    client.create_signer(signer).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_create_signer_rejects_invalid_fields() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = Client::new("some_access_token", Some(&format!("{}/", server.uri())));
+    let signer = NewSigner::new("John Doe", "john@example.com").phone_number("(11) 3333-3333");
+    let error = client.create_signer(signer).await.unwrap_err();
+
+    assert!(matches!(&error, Error::InvalidFields(errors) if errors[0].field == "phone_number"));
+    assert_eq!(
+        "Invalid fields: phone_number: must be a mobile number with 11 digits, e.g. 11999999999",
+        error.to_string()
+    );
+}
+
+#[test]
+fn test_signer_response_round_trips() {
+    let payload = json!({
+        "key": "79301388-9567-4320-90ce-9e6f60e70d28",
+        "email": "fulano@example.com",
+        "auths": ["email"],
+        "name": "Marcos Zumba",
+        "documentation": "123.321.123-40",
+        "has_documentation": true,
+        "created_at": "2021-10-20T10:00:00.000-03:00",
+        "communicate_by": "email",
+        "facial_biometrics_enabled": false
+    });
+    let signer: SignerResponse = serde_json::from_value(payload.clone()).unwrap();
+
+    assert_eq!(None, signer.birthday);
+    assert_eq!(Some(&json!("email")), signer.extra.get("communicate_by"));
+    assert_eq!(payload, serde_json::to_value(&signer).unwrap());
+}
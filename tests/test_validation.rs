@@ -0,0 +1,152 @@
+use clicksign::models::enums::AuthMethod;
+use clicksign::models::signers::NewSigner;
+use clicksign::validation::{
+    format_cnpj, format_cpf, is_valid_birthday, is_valid_cnpj, is_valid_cpf, is_valid_email,
+    normalize_phone_number, FieldError,
+};
+use clicksign::Error;
+
+#[test]
+fn test_cpf_check_digits() {
+    assert!(is_valid_cpf("123.321.123-40"));
+    assert!(is_valid_cpf("12332112340"));
+    assert!(!is_valid_cpf("123.321.123-04"));
+    assert!(!is_valid_cpf("111.111.111-11"));
+    assert!(!is_valid_cpf("1233211234"));
+    assert!(!is_valid_cpf("123.321.123-4a"));
+    assert_eq!(
+        Some("123.321.123-40".to_string()),
+        format_cpf("123 321 123 40")
+    );
+}
+
+#[test]
+fn test_cnpj_check_digits() {
+    assert!(is_valid_cnpj("11.222.333/0001-81"));
+    assert!(is_valid_cnpj("11222333000181"));
+    assert!(!is_valid_cnpj("11.222.333/0001-18"));
+    assert!(!is_valid_cnpj("00.000.000/0000-00"));
+    assert_eq!(None, format_cnpj("123.321.123-40"));
+}
+
+#[test]
+fn test_normalize_phone_number() {
+    for phone_number in [
+        "11999999999",
+        "(11) 99999-9999",
+        "+55 11 99999-9999",
+        "5511999999999",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Some("11999999999".to_string()),
+            normalize_phone_number(phone_number)
+        );
+    }
+    for phone_number in [
+        "",
+        "1199999999",
+        "+1 11 99999-9999",
+        "01999999999",
+        "11899999999",
+        "11 99999-999x",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            None,
+            normalize_phone_number(phone_number),
+            "{}",
+            phone_number
+        );
+    }
+}
+
+#[test]
+fn test_email_syntax() {
+    assert!(is_valid_email("fulano.de.tal+clicksign@mail.example.com"));
+    for email in [
+        "",
+        "fulano",
+        "@example.com",
+        "fulano@",
+        "fulano@@example.com",
+        "ful ano@example.com",
+        ".fulano@example.com",
+        "fulano@example..com",
+        "fulano@-example.com",
+    ]
+    .iter()
+    {
+        assert!(!is_valid_email(email), "{}", email);
+    }
+}
+
+#[test]
+fn test_birthday_sanity() {
+    assert!(is_valid_birthday(&"1983-03-31".parse().unwrap()));
+    assert!(!is_valid_birthday(&"2999-01-01".parse().unwrap()));
+    assert!(!is_valid_birthday(&"1850-01-01".parse().unwrap()));
+}
+
+#[test]
+fn test_signer_builder_normalizes_fields() {
+    let signer = NewSigner::new("Marcos Zumba", "fulano@example.com")
+        .auths(&[AuthMethod::Whatsapp])
+        .phone_number("+55 (11) 99999-9999")
+        .documentation("12332112340");
+
+    assert_eq!(Some("11999999999".to_string()), signer.phone_number);
+    assert_eq!(Some("123.321.123-40".to_string()), signer.documentation);
+    assert!(signer.validate().is_ok());
+}
+
+#[test]
+fn test_signer_validation_accepts_unformatted_fields() {
+    let signer = NewSigner {
+        phone_number: Some("+55 (11) 99999-9999".to_string()),
+        documentation: Some("12332112340".to_string()),
+        ..NewSigner::new("Marcos Zumba", "fulano@example.com")
+    };
+    assert!(signer.validate().is_ok());
+
+    let signer = signer.normalized();
+    assert_eq!(Some("11999999999".to_string()), signer.phone_number);
+    assert_eq!(Some("123.321.123-40".to_string()), signer.documentation);
+
+    let signer: NewSigner = serde_json::from_value(serde_json::json!({
+        "email": "fulano@example.com",
+        "phone_number": "(11) 99999-9999",
+        "auths": ["sms"],
+        "name": "Marcos Zumba",
+        "documentation": "123.321.123-40"
+    }))
+    .unwrap();
+    assert!(signer.validate().is_ok());
+}
+
+#[test]
+fn test_signer_validation_reports_every_field() {
+    let signer = NewSigner::new("", "fulano@example")
+        .auths(&[AuthMethod::Sms])
+        .documentation("123.321.123-41")
+        .birthday("2999-01-01".parse().unwrap());
+
+    let errors = match signer.validate() {
+        Err(Error::InvalidFields(errors)) => errors,
+        result => panic!("Unexpected result: {:?}", result),
+    };
+    let fields: Vec<&str> = errors.iter().map(|error| error.field).collect();
+    assert_eq!(
+        vec!["name", "email", "phone_number", "documentation", "birthday"],
+        fields
+    );
+    assert_eq!(
+        FieldError {
+            field: "phone_number",
+            message: "is required for SMS and Whatsapp authentication".to_string()
+        },
+        errors[2]
+    );
+}
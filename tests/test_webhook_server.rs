@@ -0,0 +1,165 @@
+#![cfg(feature = "webhook-server")]
+
+use clicksign::crypto::hmac_sha256_hex;
+use clicksign::models::documents::Document;
+use clicksign::models::events::{
+    AcceptanceTermData, Event, MalformedEvent, SignerActionData, UserActionData,
+};
+use clicksign::dedupe::MemoryDedupeStore;
+use clicksign::webhook_server::{
+    async_trait, router, router_with_dedupe, HandlerResult, WebhookHandler,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SECRET: &str = "some_webhook_secret";
+
+#[derive(Default)]
+struct Counter {
+    closes: Arc<AtomicUsize>,
+    completed_terms: Arc<AtomicUsize>,
+    malformed: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl WebhookHandler for Counter {
+    async fn on_close(&self, _event: Event<UserActionData>, _document: Document) -> HandlerResult {
+        self.closes.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn on_sign(&self, _event: Event<SignerActionData>, _document: Document) -> HandlerResult {
+        Err("database is down".into())
+    }
+
+    async fn on_acceptance_term_completed(
+        &self,
+        _event: Event<AcceptanceTermData>,
+        _document: Document,
+    ) -> HandlerResult {
+        self.completed_terms.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn on_malformed(&self, _event: MalformedEvent, _document: Document) -> HandlerResult {
+        self.malformed.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+fn body(name: &str) -> String {
+    format!(
+        r#"{{"event": {{"name": "{}", "data": {{}}, "occurred_at": "2021-10-21T10:00:00.000-03:00"}},
+            "document": {{"key": "27b02527-a576-46ee-b01c-bb4e694036c4", "path": "/a.pdf"}}}}"#,
+        name
+    )
+}
+
+async fn serve_router(app: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let app = axum::Router::new().nest("/webhooks", app);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/webhooks", address)
+}
+
+async fn post(url: &str, body: String, secret: &str) -> u16 {
+    let content_hmac = format!("sha256={}", hmac_sha256_hex(secret.as_bytes(), body.as_bytes()));
+    reqwest::Client::new()
+        .post(url)
+        .header("Content-Hmac", content_hmac)
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+#[tokio::test]
+async fn test_router_dispatches_events() {
+    let handler = Counter::default();
+    let closes = handler.closes.clone();
+    let completed_terms = handler.completed_terms.clone();
+    let url = serve_router(router(handler, SECRET)).await;
+
+    assert_eq!(200, post(&url, body("close"), SECRET).await);
+    assert_eq!(200, post(&url, body("acceptance_term_completed"), SECRET).await);
+    assert_eq!(200, post(&url, body("acceptance_term_sent"), SECRET).await);
+    assert_eq!(200, post(&url, body("some_new_event"), SECRET).await);
+    assert_eq!(1, closes.load(Ordering::SeqCst));
+    assert_eq!(1, completed_terms.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_router_status_codes() {
+    let url = serve_router(router(Counter::default(), SECRET)).await;
+
+    assert_eq!(401, post(&url, body("close"), "other_secret").await);
+    assert_eq!(400, post(&url, "not json".to_string(), SECRET).await);
+    assert_eq!(500, post(&url, body("sign"), SECRET).await);
+}
+
+#[tokio::test]
+async fn test_router_dispatches_malformed_known_event() {
+    let handler = Counter::default();
+    let closes = handler.closes.clone();
+    let malformed = handler.malformed.clone();
+    let url = serve_router(router(handler, SECRET)).await;
+    let body = r#"{"event": {"name": "close", "data": {"user": "not an object"}, "occurred_at": "2021-10-21T10:00:00.000-03:00"},
+        "document": {"key": "27b02527-a576-46ee-b01c-bb4e694036c4", "path": "/a.pdf"}}"#;
+
+    assert_eq!(200, post(&url, body.to_string(), SECRET).await);
+    assert_eq!(0, closes.load(Ordering::SeqCst));
+    assert_eq!(1, malformed.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_router_with_dedupe_handles_retries_once() {
+    let handler = Counter::default();
+    let closes = handler.closes.clone();
+    let store = MemoryDedupeStore::new(Duration::from_secs(60));
+    let url = serve_router(router_with_dedupe(handler, SECRET, store)).await;
+
+    assert_eq!(200, post(&url, body("close"), SECRET).await);
+    assert_eq!(200, post(&url, body("close"), SECRET).await);
+    assert_eq!(1, closes.load(Ordering::SeqCst));
+}
+
+/// Fails the first close, after a while, and handles the next ones
+#[derive(Default)]
+struct FailsFirstClose {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl WebhookHandler for FailsFirstClose {
+    async fn on_close(&self, _event: Event<UserActionData>, _document: Document) -> HandlerResult {
+        if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            return Err("database is down".into());
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_router_with_dedupe_concurrent_deliveries_when_first_fails() {
+    let handler = FailsFirstClose::default();
+    let calls = handler.calls.clone();
+    let store = MemoryDedupeStore::new(Duration::from_secs(60));
+    let url = serve_router(router_with_dedupe(handler, SECRET, store)).await;
+
+    let first = {
+        let url = url.clone();
+        tokio::spawn(async move { post(&url, body("close"), SECRET).await })
+    };
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(409, post(&url, body("close"), SECRET).await);
+    assert_eq!(500, first.await.unwrap());
+
+    assert_eq!(200, post(&url, body("close"), SECRET).await);
+    assert_eq!(200, post(&url, body("close"), SECRET).await);
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+}
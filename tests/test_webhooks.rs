@@ -0,0 +1,52 @@
+use clicksign::crypto::hmac_sha256_hex;
+use clicksign::models::events::DocumentEvent;
+use clicksign::webhooks::{parse_webhook, verify_signature};
+use clicksign::Error;
+
+const SECRET: &str = "some_webhook_secret";
+
+fn body() -> Vec<u8> {
+    br#"{
+        "event": {
+            "name": "close",
+            "data": {
+                "user": { "email": "admin@example.com", "name": "Admin" },
+                "account": { "key": "a1b2c3" }
+            },
+            "occurred_at": "2021-10-21T10:00:00.000-03:00"
+        },
+        "document": {
+            "key": "27b02527-a576-46ee-b01c-bb4e694036c4",
+            "path": "/Contratos/Contrato-123.pdf",
+            "status": "closed"
+        }
+    }"#
+    .to_vec()
+}
+
+#[test]
+fn test_parse_webhook() {
+    let body = body();
+    let content_hmac = format!("sha256={}", hmac_sha256_hex(SECRET.as_bytes(), &body));
+
+    let payload = parse_webhook(&body, &content_hmac, SECRET).unwrap();
+
+    assert_eq!("close", payload.event.name());
+    assert!(matches!(payload.event, DocumentEvent::Close(_)));
+    assert_eq!("27b02527-a576-46ee-b01c-bb4e694036c4", payload.document.key.unwrap());
+}
+
+#[test]
+fn test_verify_signature_rejects_tampered_body() {
+    let body = body();
+    let content_hmac = format!("sha256={}", hmac_sha256_hex(SECRET.as_bytes(), &body));
+    let mut tampered = body.clone();
+    tampered.push(b' ');
+
+    assert!(matches!(
+        verify_signature(&tampered, &content_hmac, SECRET),
+        Err(Error::InvalidSignature)
+    ));
+    assert!(verify_signature(&body, &content_hmac, "other_secret").is_err());
+    assert!(verify_signature(&body, content_hmac.trim_start_matches("sha256="), SECRET).is_err());
+}